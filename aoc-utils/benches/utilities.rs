@@ -0,0 +1,116 @@
+//! divan micro-benchmarks for the library's input, parsing and grid utilities.
+//!
+//! Run with `cargo bench --bench utilities`.
+
+use std::{fmt::Write, hint::black_box, path::PathBuf, sync::LazyLock};
+
+use aoc_utils::grid::Grid2D;
+
+fn main() {
+    divan::main();
+}
+
+/// Ten thousand lines of mixed text and integers, written once to the temp dir.
+static INPUT: LazyLock<PathBuf> = LazyLock::new(|| {
+    let text = (0..10_000).fold(String::new(), |mut text, i| {
+        let _ = writeln!(text, "move {i} from {} to -{}", i % 97, i % 13);
+        text
+    });
+    let path = std::env::temp_dir().join(format!("aoc-utils-bench-{}.txt", std::process::id()));
+    std::fs::write(&path, text).expect("failed to write benchmark input");
+    path
+});
+
+/// A 1000×1000 grid of digits.
+static GRID: LazyLock<Grid2D<u8>> = LazyLock::new(|| {
+    let lines = (0..1000).map(|y| (0..1000).map(|x| char::from(b'0' + u8::try_from((x * y) % 10).unwrap_or(0))).collect::<String>());
+    Grid2D::<u8>::from_lines(lines).expect("benchmark grid is rectangular")
+});
+
+mod read {
+    use crate::INPUT;
+
+    #[divan::bench]
+    fn read_lines() -> usize {
+        aoc_utils::read_lines(&*INPUT).expect("input exists").count()
+    }
+
+    #[divan::bench]
+    fn read_to_string_then_lines() -> usize {
+        aoc_utils::read_to_string(&*INPUT).expect("input exists").lines().count()
+    }
+
+    #[divan::bench]
+    fn input_lines() -> usize {
+        aoc_utils::input::Input::from_path(&*INPUT).expect("input exists").lines().count()
+    }
+
+    #[divan::bench]
+    fn read_bytes() -> usize {
+        aoc_utils::read_bytes(&*INPUT).expect("input exists").len()
+    }
+}
+
+mod parse {
+    use std::{hint::black_box, sync::LazyLock};
+
+    use crate::INPUT;
+
+    static TEXT: LazyLock<String> = LazyLock::new(|| aoc_utils::read_to_string(&*INPUT).expect("input exists"));
+
+    #[divan::bench]
+    fn ints() -> i64 {
+        black_box(&*TEXT).lines().flat_map(aoc_utils::ints).sum()
+    }
+
+    #[divan::bench]
+    fn split_whitespace_parse() -> i64 {
+        black_box(&*TEXT).split_whitespace().filter_map(|token| token.parse::<i64>().ok()).sum()
+    }
+}
+
+mod grid {
+    use std::hint::black_box;
+
+    use crate::GRID;
+
+    #[divan::bench]
+    fn get() -> u64 {
+        let grid = black_box(&*GRID);
+        let mut sum = 0;
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                sum += u64::from(grid.get((x, y)).copied().unwrap_or(0));
+            }
+        }
+        sum
+    }
+
+    #[divan::bench]
+    fn index() -> u64 {
+        let grid = black_box(&*GRID);
+        let mut sum = 0;
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                sum += u64::from(grid[(x, y)]);
+            }
+        }
+        sum
+    }
+
+    #[divan::bench]
+    fn iter() -> u64 {
+        black_box(&*GRID).iter().map(|(_, &cell)| u64::from(cell)).sum()
+    }
+
+    #[divan::bench]
+    fn rows() -> u64 {
+        black_box(&*GRID).rows().flatten().map(|&cell| u64::from(cell)).sum()
+    }
+}
+
+#[divan::bench]
+fn grid_from_lines() -> usize {
+    let lines = (0..1000).map(|_| "0123456789".repeat(100));
+    black_box(Grid2D::<u8>::from_lines(lines).expect("rectangular").width())
+}
@@ -0,0 +1,12 @@
+use std::io;
+
+use crate::{InputError, Lines};
+
+/// Reads puzzle input straight from the system clipboard, handy for pasted examples.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_clipboard_lines() -> Result<Lines, InputError> {
+    let text = arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| InputError::new("<clipboard>", io::Error::other(e)))?;
+    Ok(Lines::new("<clipboard>", Box::new(io::Cursor::new(text))))
+}
@@ -0,0 +1,62 @@
+use std::{fs::File, io::{self, BufRead}};
+
+#[cfg(feature = "gzip")]
+#[allow(clippy::unnecessary_wraps)]
+pub fn gzip(file: File) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(io::BufReader::new(flate2::read::MultiGzDecoder::new(file))))
+}
+
+#[cfg(not(feature = "gzip"))]
+pub fn gzip(_file: File) -> io::Result<Box<dyn BufRead>> {
+    Err(unsupported("gzip"))
+}
+
+#[cfg(feature = "zstd")]
+pub fn zstd(file: File) -> io::Result<Box<dyn BufRead>> {
+    Ok(Box::new(io::BufReader::new(zstd::Decoder::new(file)?)))
+}
+
+#[cfg(not(feature = "zstd"))]
+pub fn zstd(_file: File) -> io::Result<Box<dyn BufRead>> {
+    Err(unsupported("zstd"))
+}
+
+#[cfg(not(all(feature = "gzip", feature = "zstd")))]
+fn unsupported(feature: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, format!("compressed input requires the `{feature}` feature"))
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::test::write_temp;
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_input_is_decompressed() {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"L68\nR48\n").unwrap();
+        let path = write_temp("input.txt.gz", encoder.finish().unwrap());
+
+        let lines: Vec<_> = crate::read_lines(&path).unwrap().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["L68", "R48"]);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_input_is_decompressed() {
+        let path = write_temp("input.txt.zst", zstd::encode_all(&b"L68\nR48\n"[..], 0).unwrap());
+        assert_eq!(crate::read_to_string(&path).unwrap(), "L68\nR48\n");
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn gzip_without_feature_is_unsupported() {
+        let path = write_temp("plain.txt.gz", "not really gzip");
+        let error = crate::read_to_string(&path).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::Unsupported);
+    }
+
+}
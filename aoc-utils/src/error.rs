@@ -0,0 +1,79 @@
+use std::{fmt, io, path::{Path, PathBuf}};
+
+/// An I/O failure while reading puzzle input, naming the file and, for line-level reads,
+/// the 1-based line it happened on.
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub source: io::Error,
+}
+
+impl InputError {
+    pub fn new<P>(path: P, source: io::Error) -> Self
+    where P: AsRef<Path>, {
+        Self { path: path.as_ref().to_path_buf(), line: None, source }
+    }
+
+    #[must_use]
+    pub const fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.path.display(), self.source),
+            None => write!(f, "{}: {}", self.path.display(), self.source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl From<InputError> for io::Error {
+    fn from(value: InputError) -> Self {
+        Self::new(value.kind(), value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::io;
+
+    use crate::error::InputError;
+
+    #[test]
+    fn display_names_path_and_line() {
+        let error = InputError::new("inputs/day1.txt", io::Error::new(io::ErrorKind::InvalidData, "bad byte"));
+        assert_eq!(error.to_string(), "inputs/day1.txt: bad byte");
+        assert_eq!(error.at_line(12).to_string(), "inputs/day1.txt:12: bad byte");
+    }
+
+    #[test]
+    fn missing_file_reports_path() {
+        let error = crate::read_lines("definitely/not/here.txt").err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert_eq!(error.line, None);
+        assert!(error.to_string().starts_with("definitely/not/here.txt: "));
+    }
+
+    #[test]
+    fn conversion_keeps_kind() {
+        let error: io::Error = InputError::new("x", io::Error::from(io::ErrorKind::NotFound)).into();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+}
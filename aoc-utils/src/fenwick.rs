@@ -0,0 +1,152 @@
+/// Counts (or any `i64`s) at `0..len` with `O(log len)` point updates and prefix sums, also known
+/// as a binary indexed tree.
+///
+/// Holding how many of each value have been seen, it also finds the `k`-th smallest of them, so
+/// it can count inversions or keep a running median.
+#[derive(Debug, Clone, Default)]
+pub struct Fenwick {
+    /// `tree[i]` holds the sum over the `i & i.wrapping_neg()` indices ending at `i - 1`.
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    /// `len` zeros.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self { tree: vec![0; len + 1] }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.tree.len().saturating_sub(1)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds `delta` to the value at `i`.
+    ///
+    /// # Panics
+    /// If `i` is out of range.
+    pub fn add(&mut self, i: usize, delta: i64) {
+        assert!(i < self.len(), "Index {i} is out of bounds for length {}.", self.len());
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// The sum of the values at `0..end`.
+    ///
+    /// # Panics
+    /// If `end` is past the end.
+    #[must_use]
+    pub fn prefix_sum(&self, end: usize) -> i64 {
+        assert!(end <= self.len(), "Prefix {end} is out of bounds for length {}.", self.len());
+        let (mut i, mut sum) = (end, 0);
+        while i > 0 {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// The sum of the values in `range`.
+    ///
+    /// # Panics
+    /// If `range` reaches past the end.
+    #[must_use]
+    pub fn range_sum(&self, range: std::ops::Range<usize>) -> i64 {
+        if range.is_empty() {
+            return 0;
+        }
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+
+    /// The value at `i`.
+    ///
+    /// # Panics
+    /// If `i` is out of range.
+    #[must_use]
+    pub fn get(&self, i: usize) -> i64 {
+        self.range_sum(i..i + 1)
+    }
+
+    /// The index holding the `k`-th item, counting from 0, when the value at each index is how
+    /// many items are there; `None` if there are no more than `k` items.
+    ///
+    /// Only meaningful while no value is negative.
+    #[must_use]
+    pub fn kth(&self, k: i64) -> Option<usize> {
+        let (mut position, mut remaining) = (0, k);
+        let mut step = self.len().checked_next_power_of_two().unwrap_or(0);
+        while step > 0 {
+            let next = position + step;
+            if next < self.tree.len() && self.tree[next] <= remaining {
+                position = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        (k >= 0 && position < self.len()).then_some(position)
+    }
+}
+
+impl FromIterator<i64> for Fenwick {
+    /// Builds the tree in linear time.
+    fn from_iter<I: IntoIterator<Item = i64>>(iter: I) -> Self {
+        let mut tree: Vec<i64> = std::iter::once(0).chain(iter).collect();
+        for i in 1..tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        Self { tree }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::fenwick::Fenwick;
+
+    #[test]
+    fn prefix_sums_follow_updates() {
+        let mut sums: Fenwick = [3, 1, 4, 1, 5, 9, 2, 6].into_iter().collect();
+        assert_eq!((sums.len(), sums.prefix_sum(0), sums.prefix_sum(8)), (8, 0, 31));
+        assert_eq!((sums.range_sum(2..5), sums.get(5)), (10, 9));
+        sums.add(3, -10);
+        assert_eq!((sums.prefix_sum(4), sums.range_sum(3..8), sums.range_sum(4..4)), (-1, 13, 0));
+        assert!(Fenwick::new(0).is_empty());
+    }
+
+    #[test]
+    fn kth_walks_the_counts() {
+        let mut counts = Fenwick::new(10);
+        for value in [7, 2, 2, 9, 4] {
+            counts.add(value, 1);
+        }
+        let sorted: Vec<Option<usize>> = (0..6).map(|k| counts.kth(k)).collect();
+        assert_eq!(sorted, [Some(2), Some(2), Some(4), Some(7), Some(9), None]);
+        assert_eq!(counts.kth(-1), None);
+    }
+
+    #[test]
+    fn counts_inversions() {
+        let values = [5_usize, 3, 8, 1, 4, 2];
+        let mut seen = Fenwick::new(10);
+        let mut inversions = 0;
+        for (i, &value) in values.iter().enumerate() {
+            inversions += i64::try_from(i).unwrap() - seen.prefix_sum(value + 1);
+            seen.add(value, 1);
+        }
+        let brute = (0..values.len()).flat_map(|i| (i + 1..values.len()).map(move |j| (i, j))).filter(|&(i, j)| values[i] > values[j]).count();
+        assert_eq!(inversions, i64::try_from(brute).unwrap());
+        assert_eq!(inversions, 10);
+    }
+
+}
@@ -0,0 +1,321 @@
+use std::{fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}, str::FromStr};
+
+/// A point (or vector) on a plane. On grids `y` grows downwards, so north is `(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scales both components.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+/// A compass direction on a grid, with north pointing up (towards row 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Self; 8] =
+        [Self::North, Self::NorthEast, Self::East, Self::SouthEast, Self::South, Self::SouthWest, Self::West, Self::NorthWest];
+
+    /// The four orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Rotates by `eighths` of a turn clockwise.
+    const fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn anticlockwise, so north becomes west and north-east becomes north-west.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// A quarter turn clockwise, so north becomes east and north-east becomes south-east.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The step one cell in this direction, with `y` growing downwards.
+    #[must_use]
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (dx, dy) = match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        };
+        Point2::new(T::from(dx), T::from(dy))
+    }
+}
+
+/// Text that isn't a direction, such as `X` in a list of moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionParseError {
+    pub input: String,
+}
+
+impl fmt::Display for DirectionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a direction", self.input)
+    }
+}
+
+impl std::error::Error for DirectionParseError {}
+
+/// Accepts `U`/`D`/`L`/`R`, arrows `^`/`v`/`<`/`>` and compass points `N`, `NE`, `E` and so on.
+impl FromStr for Direction {
+    type Err = DirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "^" | "N" => Ok(Self::North),
+            "NE" => Ok(Self::NorthEast),
+            "R" | ">" | "E" => Ok(Self::East),
+            "SE" => Ok(Self::SouthEast),
+            "D" | "v" | "S" => Ok(Self::South),
+            "SW" => Ok(Self::SouthWest),
+            "L" | "<" | "W" => Ok(Self::West),
+            "NW" => Ok(Self::NorthWest),
+            _ => Err(DirectionParseError { input: s.to_owned() }),
+        }
+    }
+}
+
+/// A point (or vector) in 3D space, e.g. a beacon, a brick corner or a cube.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> Point3<T>
+where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    #[must_use]
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The vector perpendicular to both, following the right-hand rule.
+    #[must_use]
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T> Point3<T>
+where T: Copy + Add<Output = T> + From<i8>,
+{
+    /// The 26 points sharing a face, edge or corner with this one, in `x`, then `y`, then `z`
+    /// order of their offsets.
+    pub fn neighbors26(self) -> impl Iterator<Item = Self> {
+        (-1..=1_i8)
+            .flat_map(|dx| (-1..=1_i8).flat_map(move |dy| (-1..=1_i8).map(move |dz| (dx, dy, dz))))
+            .filter(|&offset| offset != (0, 0, 0))
+            .map(move |(dx, dy, dz)| self + Self::new(dx.into(), dy.into(), dz.into()))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Scales every component.
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point3<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashSet;
+
+    use crate::geometry::{Direction, DirectionParseError, Point2, Point3};
+
+    #[test]
+    fn turns_rotate_a_quarter() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::SouthWest.reverse(), Direction::NorthEast);
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.offset::<i32>() + d.reverse().offset(), Point2::new(0, 0));
+        }
+    }
+
+    #[test]
+    fn offsets_point_down_the_rows() {
+        assert_eq!(Direction::North.offset(), Point2::new(0_i64, -1));
+        assert_eq!(Direction::SouthEast.offset(), Point2::new(1_i64, 1));
+        assert_eq!(Point2::new(3, 4) + Direction::West.offset() * 2, Point2::new(1, 4));
+    }
+
+    #[test]
+    fn directions_parse_from_letters_and_arrows() {
+        let moves: Vec<Direction> = ["U", "v", "<", "R", "NW"].iter().map(|s| s.parse().unwrap()).collect();
+        assert_eq!(moves, [Direction::North, Direction::South, Direction::West, Direction::East, Direction::NorthWest]);
+        assert_eq!("x".parse::<Direction>(), Err(DirectionParseError { input: "x".to_owned() }));
+    }
+
+    #[test]
+    fn arithmetic_is_component_wise() {
+        let mut p = Point3::new(1, 2, 3);
+        assert_eq!(p + Point3::new(10, 20, 30), Point3::new(11, 22, 33));
+        assert_eq!(p - Point3::new(1, 1, 1), Point3::new(0, 1, 2));
+        assert_eq!(-p, Point3::new(-1, -2, -3));
+        assert_eq!(p * 2, Point3::new(2, 4, 6));
+        p += Point3::from((1, 1, 1));
+        p -= Point3::new(0, 0, 4);
+        assert_eq!(p, Point3::new(2, 3, 0));
+    }
+
+    #[test]
+    fn dot_and_cross_products() {
+        let (x, y, z) = (Point3::new(1, 0, 0), Point3::new(0, 1, 0), Point3::new(0, 0, 1));
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(x), -z);
+        assert_eq!(x.dot(y), 0);
+        assert_eq!(Point3::new(1, 2, 3).dot(Point3::new(4, -5, 6)), 12);
+    }
+
+    #[test]
+    fn neighbors26_surround_the_point() {
+        let p = Point3::new(5_i64, -2, 0);
+        let neighbors: HashSet<_> = p.neighbors26().collect();
+        assert_eq!(neighbors.len(), 26);
+        assert!(!neighbors.contains(&p));
+        assert!(neighbors.contains(&Point3::new(4, -3, -1)));
+        assert!(neighbors.contains(&Point3::new(6, -1, 1)));
+        assert_eq!(p.neighbors26().next(), Some(Point3::new(4, -3, -1)));
+    }
+
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashMap, fmt, hash::Hash, str::FromStr};
+
+use crate::graph::strongly_connected;
+
+/// A directed graph of named nodes with an `E` on every edge, such as a cost. Undirected graphs
+/// hold each edge both ways.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    /// Each node's outgoing edges, by the index of the node they lead to.
+    adjacent: Vec<Vec<(usize, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self { index: HashMap::new(), nodes: Vec::new(), adjacent: Vec::new() }
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from directed `(from, to, edge)` triples.
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N, E)>) -> Self {
+        let mut graph = Self::new();
+        for (from, to, edge) in edges {
+            graph.add_edge(from, to, edge);
+        }
+        graph
+    }
+
+    /// `node`'s index, adding it without edges if it's new.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        self.index.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.adjacent.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    /// Adds an edge from `from` to `to`, adding either node if it's new.
+    pub fn add_edge(&mut self, from: N, to: N, edge: E) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.adjacent[from].push((to, edge));
+    }
+
+    /// Adds an edge each way between `a` and `b`.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, edge: E)
+    where E: Clone, {
+        let (a, b) = (self.add_node(a), self.add_node(b));
+        self.adjacent[a].push((b, edge.clone()));
+        self.adjacent[b].push((a, edge));
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// `node`'s index, numbered in the order nodes were added.
+    #[must_use]
+    pub fn id(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    /// The nodes in the order they were added, so a node's index is its position.
+    #[must_use]
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// The nodes `node` has an edge to, with those edges; none if `node` is unknown.
+    pub fn neighbors(&self, node: &N) -> impl Iterator<Item = (&N, &E)> {
+        self.id(node).map_or(&[][..], |i| &self.adjacent[i]).iter().map(|(to, edge)| (&self.nodes[*to], edge))
+    }
+
+    /// How many edges leave `node`, 0 if it's unknown.
+    #[must_use]
+    pub fn degree(&self, node: &N) -> usize {
+        self.id(node).map_or(0, |i| self.adjacent[i].len())
+    }
+
+    /// The graph's strongly connected components, as [`strongly_connected`] finds them.
+    #[must_use]
+    pub fn strongly_connected(&self) -> Vec<Vec<&N>> {
+        strongly_connected(0..self.len(), |&i| self.adjacent[i].iter().map(|&(to, _)| to).collect::<Vec<_>>())
+            .into_iter()
+            .map(|component| component.into_iter().map(|i| &self.nodes[i]).collect())
+            .collect()
+    }
+}
+
+impl Graph<String> {
+    /// Reads an undirected graph from lines like `kh-tc`, the two names either side of
+    /// `separator`. Blank lines are skipped.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse_undirected<I, S>(lines: I, separator: &str) -> Result<Self, EdgeParseError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, {
+        let mut graph = Self::new();
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (a, b) = line.split_once(separator).ok_or_else(|| EdgeParseError::new(i, line))?;
+            graph.add_undirected_edge(a.trim().to_owned(), b.trim().to_owned(), ());
+        }
+        Ok(graph)
+    }
+}
+
+impl<W: FromStr> Graph<String, W> {
+    /// Reads a directed graph from lines like `a -> b (5)`, the names either side of `separator`
+    /// and the edge's weight in brackets at the end. Blank lines are skipped.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn parse_weighted<I, S>(lines: I, separator: &str) -> Result<Self, EdgeParseError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, {
+        let mut graph = Self::new();
+        for (i, line) in lines.into_iter().enumerate() {
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let edge = line.split_once(separator).and_then(|(from, rest)| {
+                let (to, weight) = rest.strip_suffix(')')?.rsplit_once('(')?;
+                Some((from.trim().to_owned(), to.trim().to_owned(), weight.trim().parse().ok()?))
+            });
+            let (from, to, weight) = edge.ok_or_else(|| EdgeParseError::new(i, line))?;
+            graph.add_edge(from, to, weight);
+        }
+        Ok(graph)
+    }
+}
+
+/// A line that isn't an edge in the expected format, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeParseError {
+    pub line: usize,
+    pub text: String,
+}
+
+impl EdgeParseError {
+    fn new(index: usize, text: &str) -> Self {
+        Self { line: index + 1, text: text.to_owned() }
+    }
+}
+
+impl fmt::Display for EdgeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} is not an edge: {:?}", self.line, self.text)
+    }
+}
+
+impl std::error::Error for EdgeParseError {}
+
+#[cfg(test)]
+mod test {
+
+    use crate::graph::{EdgeParseError, Graph};
+
+    #[test]
+    fn nodes_are_interned_by_name() {
+        let mut graph = Graph::from_edges([("a", "b", 3), ("a", "c", 1), ("c", "a", 2)]);
+        graph.add_node("d");
+        assert_eq!(graph.nodes(), ["a", "b", "c", "d"]);
+        assert_eq!((graph.id(&"c"), graph.id(&"z")), (Some(2), None));
+        assert_eq!(graph.neighbors(&"a").collect::<Vec<_>>(), vec![(&"b", &3), (&"c", &1)]);
+        assert_eq!((graph.degree(&"a"), graph.degree(&"b"), graph.degree(&"z")), (2, 0, 0));
+        assert_eq!(graph.neighbors(&"z").count(), 0);
+        assert_eq!(graph.strongly_connected(), vec![vec![&"b"], vec![&"a", &"c"], vec![&"d"]]);
+    }
+
+    #[test]
+    fn undirected_edges_parse_from_pairs() {
+        let graph = Graph::parse_undirected(["kh-tc", "qp-kh", "", "de-cg"], "-").unwrap();
+        assert_eq!(graph.len(), 5);
+        assert!(graph.contains(&"kh".to_owned()));
+        let mut neighbors: Vec<&String> = graph.neighbors(&"kh".to_owned()).map(|(n, ())| n).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, ["qp", "tc"]);
+        assert_eq!(Graph::parse_undirected(["a-b", "c"], "-").unwrap_err(), EdgeParseError { line: 2, text: "c".to_owned() });
+    }
+
+    #[test]
+    fn weighted_edges_parse_with_their_cost() {
+        let graph: Graph<String, u32> = Graph::parse_weighted(["AA -> BB (5)", "BB -> CC (12)"], "->").unwrap();
+        assert_eq!(graph.neighbors(&"AA".to_owned()).collect::<Vec<_>>(), vec![(&"BB".to_owned(), &5)]);
+        assert_eq!(graph.degree(&"CC".to_owned()), 0);
+        assert_eq!(Graph::<String, u32>::parse_weighted(["AA -> BB (x)"], "->").unwrap_err().line, 1);
+        assert!(Graph::<String, u32>::parse_weighted(["AA -> BB"], "->").is_err());
+    }
+
+}
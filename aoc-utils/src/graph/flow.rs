@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+
+/// A network of capacity-limited edges between nodes `0..len`, for maximum flow and minimum cut
+/// by Dinic's algorithm.
+#[derive(Debug, Clone)]
+pub struct FlowNetwork {
+    /// Each node's outgoing edges, as indices into `edges`.
+    adjacent: Vec<Vec<usize>>,
+    /// Edges in pairs: an edge at an even index, its reverse right after it.
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone)]
+struct Edge {
+    to: usize,
+    /// Capacity left over after the flow already pushed along it.
+    residual: u64,
+}
+
+impl FlowNetwork {
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self { adjacent: vec![Vec::new(); len], edges: Vec::new() }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.adjacent.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.adjacent.is_empty()
+    }
+
+    /// Adds an edge that can carry up to `capacity` from `from` to `to`.
+    ///
+    /// # Panics
+    /// If either node is out of range.
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: u64) {
+        self.add_pair(from, to, capacity, 0);
+    }
+
+    /// Adds an edge that can carry up to `capacity` either way, such as a wire to cut.
+    ///
+    /// # Panics
+    /// If either node is out of range.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, capacity: u64) {
+        self.add_pair(a, b, capacity, capacity);
+    }
+
+    fn add_pair(&mut self, from: usize, to: usize, forward: u64, backward: u64) {
+        self.adjacent[from].push(self.edges.len());
+        self.edges.push(Edge { to, residual: forward });
+        self.adjacent[to].push(self.edges.len());
+        self.edges.push(Edge { to: from, residual: backward });
+    }
+
+    /// Pushes as much flow as possible from `source` to `sink`, returning how much.
+    ///
+    /// The flow stays in the network: calling again only finds what more can be pushed, and
+    /// [`Self::min_cut`] reads the cut it leaves.
+    ///
+    /// # Panics
+    /// If either node is out of range.
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> u64 {
+        let mut total = 0;
+        if source == sink {
+            return total;
+        }
+        while let Some(levels) = self.levels(source, sink) {
+            let mut next_edge = vec![0; self.len()];
+            while let Some(pushed) = self.augment(source, sink, u64::MAX, &levels, &mut next_edge) {
+                total += pushed;
+            }
+        }
+        total
+    }
+
+    /// Each node's distance from `source` along edges with capacity left, or `None` once `sink`
+    /// can't be reached.
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<Option<usize>>> {
+        let mut levels = vec![None; self.len()];
+        levels[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            for &e in &self.adjacent[node] {
+                let Edge { to, residual } = self.edges[e];
+                if residual > 0 && levels[to].is_none() {
+                    levels[to] = levels[node].map(|l| l + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+        levels[sink].map(|_| levels)
+    }
+
+    /// Pushes up to `limit` along one path that climbs a level at every step, skipping edges
+    /// already found to be dead ends. `None` once no such path is left.
+    fn augment(&mut self, node: usize, sink: usize, limit: u64, levels: &[Option<usize>], next_edge: &mut [usize]) -> Option<u64> {
+        if node == sink {
+            return Some(limit);
+        }
+        while let Some(&e) = self.adjacent[node].get(next_edge[node]) {
+            let Edge { to, residual } = self.edges[e];
+            if residual > 0
+                && levels[to] == levels[node].map(|l| l + 1)
+                && let Some(pushed) = self.augment(to, sink, limit.min(residual), levels, next_edge)
+            {
+                self.edges[e].residual -= pushed;
+                self.edges[e ^ 1].residual += pushed;
+                return Some(pushed);
+            }
+            next_edge[node] += 1;
+        }
+        None
+    }
+
+    /// The nodes `source` can still reach after [`Self::max_flow`]: one side of a minimum cut,
+    /// the saturated edges leaving them being the cut itself.
+    ///
+    /// # Panics
+    /// If `source` is out of range.
+    #[must_use]
+    pub fn min_cut(&self, source: usize) -> Vec<usize> {
+        let mut reached = vec![false; self.len()];
+        reached[source] = true;
+        let mut stack = vec![source];
+        while let Some(node) = stack.pop() {
+            for &e in &self.adjacent[node] {
+                let Edge { to, residual } = self.edges[e];
+                if residual > 0 && !reached[to] {
+                    reached[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        (0..self.len()).filter(|&n| reached[n]).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::graph::flow::FlowNetwork;
+
+    #[test]
+    fn max_flow_of_a_classic_network() {
+        let mut network = FlowNetwork::new(6);
+        for (from, to, capacity) in [(0, 1, 16), (0, 2, 13), (1, 2, 10), (2, 1, 4), (1, 3, 12), (2, 4, 14), (3, 2, 9), (4, 3, 7), (3, 5, 20), (4, 5, 4)] {
+            network.add_edge(from, to, capacity);
+        }
+        assert_eq!(network.max_flow(0, 5), 23);
+        assert_eq!(network.max_flow(0, 5), 0);
+        assert_eq!(network.min_cut(0), vec![0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn min_cut_splits_two_clusters() {
+        // Two 5-cliques joined by three wires, as in the cut-three-wires puzzles.
+        let mut network = FlowNetwork::new(10);
+        for cluster in [0, 5] {
+            for a in cluster..cluster + 5 {
+                for b in a + 1..cluster + 5 {
+                    network.add_undirected_edge(a, b, 1);
+                }
+            }
+        }
+        for (a, b) in [(0, 5), (1, 6), (2, 7)] {
+            network.add_undirected_edge(a, b, 1);
+        }
+        assert_eq!(network.max_flow(4, 9), 3);
+        assert_eq!(network.min_cut(4), vec![0, 1, 2, 3, 4]);
+        assert_eq!(FlowNetwork::new(2).max_flow(0, 1), 0);
+    }
+
+}
@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use crate::graph::strongly_connected;
+
+/// A largest set of pairs between left nodes `0..adjacent.len()` and right nodes `0..right_len`
+/// with no node in two pairs, where `adjacent[l]` lists the right nodes `l` may pair with.
+///
+/// Returns each left node's partner, by Hopcroft–Karp.
+///
+/// # Panics
+/// If `adjacent` names a right node not below `right_len`.
+#[must_use]
+pub fn max_matching(adjacent: &[Vec<usize>], right_len: usize) -> Vec<Option<usize>> {
+    let mut matcher = Matcher { adjacent, left: vec![None; adjacent.len()], right: vec![None; right_len], depth: Vec::new() };
+    while matcher.layer() {
+        for l in 0..adjacent.len() {
+            if matcher.left[l].is_none() {
+                matcher.augment(l);
+            }
+        }
+    }
+    matcher.left
+}
+
+/// The one way to pair every left node, as in puzzles that narrow down which allergen is in
+/// which ingredient. `None` if there is no such pairing or more than one.
+///
+/// # Panics
+/// If `adjacent` names a right node not below `right_len`.
+#[must_use]
+pub fn unique_matching(adjacent: &[Vec<usize>], right_len: usize) -> Option<Vec<usize>> {
+    let matching: Vec<usize> = max_matching(adjacent, right_len).into_iter().collect::<Option<_>>()?;
+    // Another pairing of every left node would differ by swapping partners along a path that
+    // alternates between unused and used pairs: left to right along an unused pair, back along a
+    // used one. The path either closes into a cycle or ends at an unpaired right node, which a
+    // sink leading back to every left node turns into a cycle too.
+    let mut partner = vec![None; right_len];
+    for (l, &r) in matching.iter().enumerate() {
+        partner[r] = Some(l);
+    }
+    let left_len = adjacent.len();
+    let sink = left_len + right_len;
+    let successors = |&node: &usize| -> Vec<usize> {
+        if node < left_len {
+            adjacent[node].iter().filter(|&&r| r != matching[node]).map(|&r| left_len + r).collect()
+        } else if node == sink {
+            (0..left_len).collect()
+        } else {
+            vec![partner[node - left_len].unwrap_or(sink)]
+        }
+    };
+    strongly_connected(0..=sink, successors).iter().all(|c| c.len() == 1).then_some(matching)
+}
+
+struct Matcher<'a> {
+    adjacent: &'a [Vec<usize>],
+    left: Vec<Option<usize>>,
+    right: Vec<Option<usize>>,
+    /// Each left node's layer in the current phase, `None` if it can't be on a shortest
+    /// augmenting path.
+    depth: Vec<Option<usize>>,
+}
+
+impl Matcher<'_> {
+    /// Layers the left nodes by breadth-first search from the unpaired ones, returning whether any
+    /// augmenting path is left.
+    fn layer(&mut self) -> bool {
+        self.depth = self.left.iter().map(|p| p.is_none().then_some(0)).collect();
+        let mut queue: VecDeque<usize> = (0..self.left.len()).filter(|&l| self.left[l].is_none()).collect();
+        let mut found = false;
+        while let Some(l) = queue.pop_front() {
+            for &r in &self.adjacent[l] {
+                match self.right[r] {
+                    None => found = true,
+                    Some(next) if self.depth[next].is_none() => {
+                        self.depth[next] = self.depth[l].map(|d| d + 1);
+                        queue.push_back(next);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        found
+    }
+
+    /// Extends the matching along a layered path from `l`, returning whether one was found.
+    fn augment(&mut self, l: usize) -> bool {
+        let adjacent = self.adjacent;
+        for &r in &adjacent[l] {
+            let free = match self.right[r] {
+                None => true,
+                Some(next) => self.depth[next] == self.depth[l].map(|d| d + 1) && self.augment(next),
+            };
+            if free {
+                self.left[l] = Some(r);
+                self.right[r] = Some(l);
+                return true;
+            }
+        }
+        self.depth[l] = None;
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::graph::matching::{max_matching, unique_matching};
+
+    #[test]
+    fn max_matching_pairs_as_many_as_possible() {
+        let adjacent = vec![vec![0, 1], vec![0], vec![0, 2], vec![2]];
+        let matching = max_matching(&adjacent, 3);
+        assert_eq!(matching.iter().flatten().count(), 3);
+        assert_eq!((matching[0], matching[1]), (Some(1), Some(0)));
+        let mut partners: Vec<usize> = matching.iter().flatten().copied().collect();
+        partners.sort_unstable();
+        assert_eq!(partners, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn unique_matching_solves_allergens() {
+        // dairy: mxmxvkd, sqjhc; fish: mxmxvkd; soy: sqjhc, fvjkl.
+        let allergens = vec![vec![0, 1], vec![0], vec![1, 2]];
+        assert_eq!(unique_matching(&allergens, 3), Some(vec![1, 0, 2]));
+        assert_eq!(unique_matching(&[vec![0, 1], vec![0, 1]], 2), None);
+        assert_eq!(unique_matching(&[vec![0], vec![0]], 2), None);
+        // Spare right nodes leave room to move a left node onto one of them.
+        assert_eq!(unique_matching(&[vec![0, 1]], 2), None);
+        assert_eq!(unique_matching(&[vec![0], vec![0, 1, 2]], 3), None);
+        assert_eq!(unique_matching(&[vec![0], vec![0, 1]], 3), Some(vec![0, 1]));
+    }
+
+}
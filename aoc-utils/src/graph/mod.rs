@@ -0,0 +1,361 @@
+mod adjacency;
+pub mod flow;
+pub mod matching;
+
+pub use adjacency::{EdgeParseError, Graph};
+
+use std::{collections::{HashMap, HashSet, VecDeque}, hash::Hash, ops::Add};
+
+/// Orders `nodes` so that for every `(before, after)` pair in `edges` `before` comes first.
+///
+/// Nodes only named in `edges` are included too, and ties keep the order nodes were first seen in.
+///
+/// # Errors
+/// A cycle, in edge order, if the edges contradict each other.
+pub fn topo_sort<N>(nodes: impl IntoIterator<Item = N>, edges: impl IntoIterator<Item = (N, N)>) -> Result<Vec<N>, Vec<N>>
+where N: Clone + Eq + Hash, {
+    let mut index = HashMap::new();
+    let mut names = Vec::new();
+    let mut id = |node: N| {
+        *index.entry(node.clone()).or_insert_with(|| {
+            names.push(node);
+            names.len() - 1
+        })
+    };
+    nodes.into_iter().for_each(|node| {
+        id(node);
+    });
+    let edges: Vec<(usize, usize)> = edges.into_iter().map(|(before, after)| (id(before), id(after))).collect();
+
+    let mut after = vec![Vec::new(); names.len()];
+    let mut before = vec![Vec::new(); names.len()];
+    for &(b, a) in &edges {
+        after[b].push(a);
+        before[a].push(b);
+    }
+
+    let mut waiting: Vec<usize> = before.iter().map(Vec::len).collect();
+    let mut ready: VecDeque<usize> = (0..names.len()).filter(|&n| waiting[n] == 0).collect();
+    let mut order = Vec::with_capacity(names.len());
+    while let Some(n) = ready.pop_front() {
+        order.push(n);
+        for &a in &after[n] {
+            waiting[a] -= 1;
+            if waiting[a] == 0 {
+                ready.push_back(a);
+            }
+        }
+    }
+    if order.len() == names.len() {
+        return Ok(order.into_iter().map(|n| names[n].clone()).collect());
+    }
+
+    // Every node left over still waits on another left-over node, so walking backwards through
+    // those must eventually come round to a node already visited.
+    let mut walked = vec![waiting.iter().position(|&w| w > 0).unwrap_or_default()];
+    loop {
+        let current = walked[walked.len() - 1];
+        let previous = before[current].iter().copied().find(|&b| waiting[b] > 0).unwrap_or_default();
+        if let Some(start) = walked.iter().position(|&n| n == previous) {
+            let mut cycle: Vec<N> = walked[start..].iter().map(|&n| names[n].clone()).collect();
+            cycle.reverse();
+            return Err(cycle);
+        }
+        walked.push(previous);
+    }
+}
+
+/// The strongly connected components reachable from `nodes`, following `successors`: groups in
+/// which every node can reach every other.
+///
+/// Uses Tarjan's algorithm, so components come out in reverse topological order, each one only
+/// leading to components listed before it. Nodes within a component are in discovery order.
+pub fn strongly_connected<N, I>(nodes: impl IntoIterator<Item = N>, mut successors: impl FnMut(&N) -> I) -> Vec<Vec<N>>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N>, {
+    let mut tarjan = Tarjan { index: HashMap::new(), names: Vec::new(), low: Vec::new(), on_stack: Vec::new(), stack: Vec::new() };
+    let mut components = Vec::new();
+    for root in nodes {
+        if tarjan.index.contains_key(&root) {
+            continue;
+        }
+        let root = tarjan.enter(root);
+        let mut calls = vec![(root, successors(&tarjan.names[root]).into_iter())];
+        while let Some((v, children)) = calls.last_mut() {
+            let v = *v;
+            if let Some(w) = children.next() {
+                match tarjan.index.get(&w) {
+                    None => {
+                        let w = tarjan.enter(w);
+                        calls.push((w, successors(&tarjan.names[w]).into_iter()));
+                    }
+                    Some(&w) if tarjan.on_stack[w] => tarjan.low[v] = tarjan.low[v].min(w),
+                    Some(_) => {}
+                }
+                continue;
+            }
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                tarjan.low[parent] = tarjan.low[parent].min(tarjan.low[v]);
+            }
+            if tarjan.low[v] == v {
+                let start = tarjan.stack.iter().rposition(|&n| n == v).unwrap_or_default();
+                let component: Vec<N> = tarjan.stack.drain(start..).map(|n| {
+                    tarjan.on_stack[n] = false;
+                    tarjan.names[n].clone()
+                }).collect();
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Tarjan's bookkeeping. Nodes are numbered in discovery order, which doubles as their index.
+struct Tarjan<N> {
+    index: HashMap<N, usize>,
+    names: Vec<N>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+}
+
+impl<N: Clone + Eq + Hash> Tarjan<N> {
+    fn enter(&mut self, node: N) -> usize {
+        let n = self.names.len();
+        self.index.insert(node.clone(), n);
+        self.names.push(node);
+        self.low.push(n);
+        self.on_stack.push(true);
+        self.stack.push(n);
+        n
+    }
+}
+
+/// The cheapest cost between every pair of nodes, by Floyd–Warshall.
+///
+/// `costs` is a square matrix where `costs[from][to]` is the edge cost, or `None` with no edge.
+/// Each node costs nothing to reach from itself. Costs must not be negative.
+///
+/// # Panics
+/// If `costs` isn't square.
+#[must_use]
+pub fn floyd_warshall<C>(mut costs: Vec<Vec<Option<C>>>) -> Vec<Vec<Option<C>>>
+where C: Copy + Ord + Default + Add<Output = C>, {
+    let n = costs.len();
+    assert!(costs.iter().all(|row| row.len() == n), "The cost matrix must be square.");
+    for (i, row) in costs.iter_mut().enumerate() {
+        row[i] = Some(C::default());
+    }
+    for k in 0..n {
+        // Row `k` can't improve by going through `k` itself, so a copy stays accurate.
+        let via = costs[k].clone();
+        for row in &mut costs {
+            let Some(to_k) = row[k] else { continue };
+            for (cell, from_k) in row.iter_mut().zip(&via) {
+                if let &Some(from_k) = from_k {
+                    let through_k = to_k + from_k;
+                    if cell.is_none_or(|direct| through_k < direct) {
+                        *cell = Some(through_k);
+                    }
+                }
+            }
+        }
+    }
+    costs
+}
+
+/// Every pairwise distance between named nodes, typically to shrink a big graph down to the few
+/// nodes of interest.
+#[derive(Debug, Clone)]
+pub struct AllPairs<N, C> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    distances: Vec<Vec<Option<C>>>,
+}
+
+impl<N, C> AllPairs<N, C>
+where N: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, {
+    /// Runs [`floyd_warshall`] over directed `(from, to, cost)` edges; list both directions for
+    /// an undirected graph. Where an edge is repeated the cheapest one counts.
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N, C)>) -> Self {
+        let mut index = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut id = |node: N| {
+            *index.entry(node.clone()).or_insert_with(|| {
+                nodes.push(node);
+                nodes.len() - 1
+            })
+        };
+        let edges: Vec<(usize, usize, C)> = edges.into_iter().map(|(from, to, cost)| (id(from), id(to), cost)).collect();
+
+        let mut costs = vec![vec![None; nodes.len()]; nodes.len()];
+        for (from, to, cost) in edges {
+            let cell: &mut Option<C> = &mut costs[from][to];
+            *cell = Some(cell.map_or(cost, |c| c.min(cost)));
+        }
+        Self { index, nodes, distances: floyd_warshall(costs) }
+    }
+
+    /// The cheapest cost from `from` to `to`, or `None` if either is unknown or `to` can't be
+    /// reached.
+    #[must_use]
+    pub fn distance(&self, from: &N, to: &N) -> Option<C> {
+        self.distances[*self.index.get(from)?][*self.index.get(to)?]
+    }
+
+    /// The nodes in the order they first appeared in the edges.
+    #[must_use]
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+}
+
+/// A largest set of nodes all adjacent to each other, sorted, where `adjacent` holds every node's
+/// neighbours in both directions. Empty if there are no nodes.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn max_clique<N>(adjacent: &HashMap<N, HashSet<N>>) -> Vec<N>
+where N: Clone + Eq + Hash + Ord, {
+    maximal_cliques(adjacent).max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a))).unwrap_or_default()
+}
+
+/// Every clique that can't be grown by another node, each sorted, in no particular order. Uses
+/// Bron–Kerbosch with pivoting; `adjacent` must list every edge in both directions.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn maximal_cliques<N>(adjacent: &HashMap<N, HashSet<N>>) -> MaximalCliques<'_, N>
+where N: Clone + Eq + Hash + Ord, {
+    MaximalCliques { adjacent, stack: vec![(Vec::new(), adjacent.keys().cloned().collect(), HashSet::new())] }
+}
+
+/// The iterator behind [`maximal_cliques`], keeping Bron–Kerbosch's calls on its own stack.
+pub struct MaximalCliques<'a, N> {
+    adjacent: &'a HashMap<N, HashSet<N>>,
+    /// The clique so far, the nodes that could extend it, and those already tried.
+    #[allow(clippy::type_complexity)]
+    stack: Vec<(Vec<N>, HashSet<N>, HashSet<N>)>,
+}
+
+impl<N> Iterator for MaximalCliques<'_, N>
+where N: Clone + Eq + Hash + Ord, {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let empty = HashSet::new();
+        while let Some((clique, mut candidates, mut excluded)) = self.stack.pop() {
+            if candidates.is_empty() && excluded.is_empty() {
+                let mut clique = clique;
+                clique.sort_unstable();
+                return Some(clique);
+            }
+            // Any maximal clique holds the pivot or one of its non-neighbours, so only those need
+            // branching on.
+            let neighbours = |n: &N| self.adjacent.get(n).unwrap_or(&empty);
+            let pivot = candidates.iter().chain(&excluded).max_by_key(|&u| neighbours(u).intersection(&candidates).count());
+            let branches: Vec<N> = candidates.iter().filter(|&v| pivot.is_none_or(|u| !neighbours(u).contains(v))).cloned().collect();
+            for v in branches {
+                let mut grown = clique.clone();
+                grown.push(v.clone());
+                let next = (grown, candidates.intersection(neighbours(&v)).cloned().collect(), excluded.intersection(neighbours(&v)).cloned().collect());
+                self.stack.push(next);
+                candidates.remove(&v);
+                excluded.insert(v);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::{HashMap, HashSet};
+
+    use crate::graph::{AllPairs, floyd_warshall, max_clique, maximal_cliques, strongly_connected, topo_sort};
+
+    fn undirected<'a>(edges: &[(&'a str, &'a str)]) -> HashMap<&'a str, HashSet<&'a str>> {
+        let mut adjacent: HashMap<_, HashSet<_>> = HashMap::new();
+        for &(a, b) in edges {
+            adjacent.entry(a).or_default().insert(b);
+            adjacent.entry(b).or_default().insert(a);
+        }
+        adjacent
+    }
+
+    #[test]
+    fn topo_sort_respects_every_edge() {
+        let edges = [('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')];
+        let order = topo_sort([], edges).unwrap();
+        assert_eq!(order, vec!['C', 'A', 'F', 'B', 'D', 'E']);
+        assert_eq!(topo_sort(['Z', 'A'], [('A', 'B')]).unwrap(), vec!['Z', 'A', 'B']);
+    }
+
+    #[test]
+    fn topo_sort_reports_a_cycle() {
+        let cycle = topo_sort([1, 2, 3, 4, 5], [(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)]).unwrap_err();
+        let start = cycle.iter().position(|&n| n == 2).unwrap();
+        assert_eq!(cycle.len(), 3);
+        assert_eq!([cycle[start], cycle[(start + 1) % 3], cycle[(start + 2) % 3]], [2, 3, 4]);
+        assert_eq!(topo_sort(['x'], [('x', 'x')]), Err(vec!['x']));
+    }
+
+    #[test]
+    fn components_come_out_sinks_first() {
+        let edges = |n: &char| match n {
+            'a' => vec!['b'],
+            'b' => vec!['c'],
+            'c' => vec!['a', 'd'],
+            'd' => vec!['e'],
+            'e' => vec!['d'],
+            'f' => vec!['a', 'f'],
+            _ => vec![],
+        };
+        assert_eq!(strongly_connected(['a', 'f', 'g'], edges), vec![vec!['d', 'e'], vec!['a', 'b', 'c'], vec!['f'], vec!['g']]);
+        assert_eq!(strongly_connected(['e'], edges), vec![vec!['e', 'd']]);
+    }
+
+    #[test]
+    fn long_cycles_do_not_overflow() {
+        let components = strongly_connected([0_u32], |&n| [(n + 1) % 200_000]);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 200_000);
+    }
+
+    #[test]
+    fn floyd_warshall_finds_every_cheapest_cost() {
+        let costs = vec![
+            vec![None, Some(3), Some(8), None],
+            vec![None, None, Some(1), None],
+            vec![Some(4), None, None, Some(2)],
+            vec![None, None, None, None],
+        ];
+        let distances = floyd_warshall(costs);
+        assert_eq!(distances[0], vec![Some(0), Some(3), Some(4), Some(6)]);
+        assert_eq!(distances[2][1], Some(7));
+        assert_eq!(distances[3], vec![None, None, None, Some(0)]);
+    }
+
+    #[test]
+    fn all_pairs_interns_node_names() {
+        let tunnels = [("AA", "DD", 1), ("DD", "CC", 1), ("CC", "BB", 1), ("AA", "BB", 5), ("BB", "JJ", 2)];
+        let distances = AllPairs::from_edges(tunnels.into_iter().flat_map(|(a, b, cost)| [(a, b, cost), (b, a, cost)]));
+        assert_eq!(distances.nodes(), ["AA", "DD", "CC", "BB", "JJ"]);
+        assert_eq!(distances.distance(&"AA", &"BB"), Some(3));
+        assert_eq!(distances.distance(&"JJ", &"DD"), Some(4));
+        assert_eq!(distances.distance(&"AA", &"ZZ"), None);
+
+        let one_way = AllPairs::from_edges([('a', 'b', 2_u64), ('a', 'b', 1)]);
+        assert_eq!((one_way.distance(&'a', &'b'), one_way.distance(&'b', &'a')), (Some(1), None));
+    }
+
+    #[test]
+    fn max_clique_finds_the_largest_fully_connected_set() {
+        let network = undirected(&[("ka", "co"), ("ta", "co"), ("de", "co"), ("ta", "ka"), ("de", "ta"), ("ka", "de"), ("de", "qp"), ("qp", "ub")]);
+        assert_eq!(max_clique(&network), vec!["co", "de", "ka", "ta"]);
+        let mut cliques: Vec<Vec<&str>> = maximal_cliques(&network).collect();
+        cliques.sort();
+        assert_eq!(cliques, vec![vec!["co", "de", "ka", "ta"], vec!["de", "qp"], vec!["qp", "ub"]]);
+        assert!(max_clique(&HashMap::<u8, HashSet<u8>>::new()).is_empty());
+    }
+
+}
@@ -0,0 +1,396 @@
+use std::{collections::HashMap, fmt, io, ops::{Index, IndexMut}};
+
+use crate::geometry::Direction;
+
+/// A rectangular grid stored row-major in a flat `Vec`, indexed by `(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+pub type CharGrid = Grid2D<char>;
+
+/// A row whose width doesn't match the first row, with its 1-based line number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRowError {
+    pub line: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl fmt::Display for RaggedRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} has width {}, expected {}", self.line, self.width, self.expected)
+    }
+}
+
+impl std::error::Error for RaggedRowError {}
+
+impl From<RaggedRowError> for io::Error {
+    fn from(value: RaggedRowError) -> Self {
+        Self::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+impl<T: Clone> Grid2D<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Swaps rows and columns, so cell `(x, y)` moves to `(y, x)`.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width).flat_map(|x| self.column(x).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    /// Mirrors left to right.
+    #[must_use]
+    pub fn flip_h(&self) -> Self {
+        let cells = self.rows().flat_map(|row| row.iter().rev().cloned()).collect();
+        Self { cells, ..*self }
+    }
+
+    /// Mirrors top to bottom.
+    #[must_use]
+    pub fn flip_v(&self) -> Self {
+        let cells = (0..self.height).rev().flat_map(|y| self.row(y).to_vec()).collect();
+        Self { cells, ..*self }
+    }
+
+    /// A quarter turn clockwise: the top row becomes the right column.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    /// A quarter turn anticlockwise: the top row becomes the left column.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    /// All eight rotations and reflections, starting with the grid itself. Symmetric grids
+    /// repeat some of them.
+    pub fn orientations(&self) -> impl Iterator<Item = Self> {
+        let turns = |grid: Self| std::iter::successors(Some(grid), |g| Some(g.rotate_cw())).take(4);
+        turns(self.clone()).chain(turns(self.flip_h()))
+    }
+
+    /// The same orientation for every grid that matches this one up to rotation and reflection,
+    /// for deduplicating patterns.
+    #[must_use]
+    pub fn canonical(&self) -> Self
+    where T: Ord, {
+        self.orientations()
+            .min_by(|a, b| (a.width, &a.cells).cmp(&(b.width, &b.cells)))
+            .unwrap_or_else(|| self.clone())
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// Wraps row-major `cells`; the length must be a multiple of `width`.
+    ///
+    /// A `width` of 0 gives no rows, as their number can't be told from the cells.
+    ///
+    /// # Panics
+    /// If `cells.len()` is not a multiple of `width`.
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "Cell count must be a multiple of the width.");
+        Self { width, height, cells }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_lines_with<I, S, F>(lines: I, mut f: F) -> Result<Self, RaggedRowError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, F: FnMut(char) -> T, {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (i, line) in lines.into_iter().enumerate() {
+            height += 1;
+            let before = cells.len();
+            cells.extend(line.as_ref().chars().map(&mut f));
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if expected != row_width => {
+                    return Err(RaggedRowError { line: i + 1, width: row_width, expected });
+                }
+                Some(_) => {}
+            }
+        }
+        // Rows are counted rather than derived from the cells, so blank lines still make a grid of
+        // zero-width rows.
+        Ok(Self { width: width.unwrap_or(0), height, cells })
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub const fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) { self.cells.get(pos.1 * self.width + pos.0) } else { None }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) { self.cells.get_mut(pos.1 * self.width + pos.0) } else { None }
+    }
+
+    /// The position one step from `pos` in `direction`, or `None` if that leaves the grid.
+    #[must_use]
+    pub fn step(&self, (x, y): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let offset = direction.offset::<isize>();
+        let next = (x.checked_add_signed(offset.x)?, y.checked_add_signed(offset.y)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid, clockwise from north.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::CARDINAL.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid, clockwise from north.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| self.step(pos, d))
+    }
+
+    /// Like [`Self::neighbors4`], with each neighbour's cell.
+    pub fn neighbor_cells4(&self, pos: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors4(pos).map(|p| (p, &self[p]))
+    }
+
+    /// Like [`Self::neighbors8`], with each neighbour's cell.
+    pub fn neighbor_cells8(&self, pos: (usize, usize)) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors8(pos).map(|p| (p, &self[p]))
+    }
+
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(if x < self.width { self.height } else { 0 })
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates every cell with its `(x, y)` position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width.max(1);
+        self.cells.iter().enumerate().map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    #[must_use]
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+}
+
+impl Grid2D<u8> {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_lines<I, S>(lines: I) -> Result<Self, RaggedRowError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (i, line) in lines.into_iter().enumerate() {
+            height += 1;
+            let row = line.as_ref().as_bytes();
+            match width {
+                None => width = Some(row.len()),
+                Some(expected) if expected != row.len() => {
+                    return Err(RaggedRowError { line: i + 1, width: row.len(), expected });
+                }
+                Some(_) => {}
+            }
+            cells.extend_from_slice(row);
+        }
+        Ok(Self { width: width.unwrap_or(0), height, cells })
+    }
+}
+
+impl Grid2D<char> {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_lines<I, S>(lines: I) -> Result<Self, RaggedRowError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, {
+        Self::from_lines_with(lines, |c| c)
+    }
+
+    /// Builds the grid and records where each of the `markers` (start, end, guards...) appears.
+    ///
+    /// Every requested marker gets an entry, empty if it never occurs; positions are in row order.
+    #[allow(clippy::missing_errors_doc, clippy::type_complexity)]
+    pub fn from_lines_with_markers<I, S>(lines: I, markers: &[char]) -> Result<(Self, HashMap<char, Vec<(usize, usize)>>), RaggedRowError>
+    where I: IntoIterator<Item = S>, S: AsRef<str>, {
+        let grid = Self::from_lines(lines)?;
+        let mut found: HashMap<char, Vec<(usize, usize)>> = markers.iter().map(|&c| (c, Vec::new())).collect();
+        for (pos, c) in grid.iter() {
+            if let Some(positions) = found.get_mut(c) {
+                positions.push(pos);
+            }
+        }
+        Ok((grid, found))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| panic!("Position {pos:?} is outside the {}x{} grid.", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("Position {pos:?} is outside the {width}x{height} grid."))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{geometry::Direction, grid::{Grid2D, RaggedRowError}};
+
+    fn sample() -> Grid2D<u8> {
+        Grid2D::<u8>::from_lines(["abc", "def"]).unwrap()
+    }
+
+    #[test]
+    fn dimensions_and_indexing() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], b'a');
+        assert_eq!(grid[(2, 1)], b'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn get_mut_writes_through() {
+        let mut grid = sample();
+        *grid.get_mut((1, 1)).unwrap() = b'X';
+        grid[(0, 0)] = b'Y';
+        assert_eq!(grid.row(1), b"dXf");
+        assert_eq!(grid.row(0), b"Ybc");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&b"abc"[..], &b"def"[..]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), b"be");
+        assert_eq!(grid.column(5).count(), 0);
+        let columns: Vec<Vec<u8>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![b"ad".to_vec(), b"be".to_vec(), b"cf".to_vec()]);
+    }
+
+    #[test]
+    fn iter_yields_positions() {
+        let grid = Grid2D::<char>::from_lines(["ab", "cd"]).unwrap();
+        let cells: Vec<_> = grid.iter().map(|(pos, c)| (pos, *c)).collect();
+        assert_eq!(cells, vec![((0, 0), 'a'), ((1, 0), 'b'), ((0, 1), 'c'), ((1, 1), 'd')]);
+    }
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let grid = Grid2D::<char>::from_lines(["abc", "def", "ghi"]).unwrap();
+        assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), vec![(1, 0), (2, 1), (1, 2), (0, 1)]);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).collect::<Vec<_>>(), vec![(2, 1), (1, 2), (1, 1)]);
+        let cells: Vec<char> = grid.neighbor_cells8((0, 2)).map(|(_, c)| *c).collect();
+        assert_eq!(cells, vec!['d', 'e', 'h']);
+        assert_eq!(grid.neighbor_cells4((2, 0)).collect::<Vec<_>>(), vec![((2, 1), &'f'), ((1, 0), &'b')]);
+        assert_eq!(grid.step((0, 1), Direction::West), None);
+    }
+
+    #[test]
+    fn blank_lines_keep_their_rows() {
+        let bytes = Grid2D::<u8>::from_lines(["", "", ""]).unwrap();
+        assert_eq!((bytes.width(), bytes.height(), bytes.rows().count()), (0, 3, 3));
+        let chars = Grid2D::<char>::from_lines(["", ""]).unwrap();
+        assert_eq!((chars.width(), chars.height(), chars.flip_v().height()), (0, 2, 2));
+        assert_eq!(chars.transpose().height(), 0);
+        assert_eq!(Grid2D::<char>::from_lines(Vec::<&str>::new()).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn ragged_lines_are_rejected() {
+        assert_eq!(
+            Grid2D::<char>::from_lines(["ab", "cd", "e"]),
+            Err(RaggedRowError { line: 3, width: 1, expected: 2 })
+        );
+    }
+
+    #[test]
+    fn from_lines_with_maps_cells() {
+        let grid = Grid2D::from_lines_with(["12", "34"], |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(grid[(1, 1)], 4);
+    }
+
+    #[test]
+    fn markers_are_indexed() {
+        let (grid, markers) = crate::grid::CharGrid::from_lines_with_markers(["S.#", "#.^", "^.E"], &['S', 'E', '^', 'G']).unwrap();
+        assert_eq!(grid[(1, 1)], '.');
+        assert_eq!(markers[&'S'], vec![(0, 0)]);
+        assert_eq!(markers[&'E'], vec![(2, 2)]);
+        assert_eq!(markers[&'^'], vec![(2, 1), (0, 2)]);
+        assert!(markers[&'G'].is_empty());
+        assert!(!markers.contains_key(&'#'));
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = sample();
+        assert_eq!(grid.flip_h().rows().collect::<Vec<_>>(), vec![&b"cba"[..], &b"fed"[..]]);
+        assert_eq!(grid.flip_v().rows().collect::<Vec<_>>(), vec![&b"def"[..], &b"abc"[..]]);
+        assert_eq!(grid.rotate_cw().rows().collect::<Vec<_>>(), vec![&b"da"[..], &b"eb"[..], &b"fc"[..]]);
+        assert_eq!(grid.rotate_ccw().rows().collect::<Vec<_>>(), vec![&b"cf"[..], &b"be"[..], &b"ad"[..]]);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_h().flip_v());
+    }
+
+    #[test]
+    fn orientations_cover_the_symmetry_group() {
+        let grid = sample();
+        let orientations: Vec<_> = grid.orientations().collect();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(orientations.iter().collect::<std::collections::HashSet<_>>().len(), 8);
+        assert!(orientations.iter().all(|o| o.canonical() == grid.canonical()));
+        assert_ne!(Grid2D::<u8>::from_lines(["abd", "cef"]).unwrap().canonical(), grid.canonical());
+    }
+
+    #[test]
+    fn transpose_swaps_axes() {
+        let grid = sample().transpose();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&b"ad"[..], &b"be"[..], &b"cf"[..]]);
+        assert_eq!(grid.transpose(), sample());
+    }
+
+}
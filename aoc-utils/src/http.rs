@@ -0,0 +1,22 @@
+use std::io::{self, BufRead};
+
+use crate::{InputError, Lines};
+
+/// Fetches `url` and streams its body line by line, e.g. a raw gist of a puzzle input.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_lines_url(url: &str) -> Result<Lines, InputError> {
+    let response = ureq::get(url).call().map_err(|e| InputError::new(url, io::Error::other(e)))?;
+    let reader: Box<dyn BufRead> = Box::new(io::BufReader::new(response.into_body().into_reader()));
+    Ok(Lines::new(url, reader))
+}
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn unreachable_url_reports_url() {
+        let error = crate::read_lines_url("http://127.0.0.1:9/day1.txt").err().unwrap();
+        assert!(error.to_string().starts_with("http://127.0.0.1:9/day1.txt: "));
+    }
+
+}
@@ -0,0 +1,174 @@
+use std::{io::{self, BufRead, Read}, path::{Path, PathBuf}, str::FromStr};
+
+use crate::{InputError, ParseError, normalize::Normalize};
+
+/// A source of puzzle input: a file, stdin, an in-memory string, or any `BufRead`.
+pub struct Input {
+    reader: Box<dyn BufRead>,
+}
+
+impl Input {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn from_path<P>(path: P) -> Result<Self, InputError>
+    where P: AsRef<Path>, {
+        Ok(Self { reader: crate::open(path)? })
+    }
+
+    #[must_use]
+    pub fn stdin() -> Self {
+        Self::from_reader(io::stdin().lock())
+    }
+
+    #[must_use]
+    pub fn from_text(text: &str) -> Self {
+        Self::from_reader(io::Cursor::new(text.to_owned()))
+    }
+
+    pub fn from_reader<R>(reader: R) -> Self
+    where R: BufRead + 'static, {
+        Self { reader: Box::new(reader) }
+    }
+
+    #[must_use]
+    pub fn lines(self) -> io::Lines<Box<dyn BufRead>> {
+        self.reader.lines()
+    }
+
+    pub fn normalized_lines(self, options: Normalize) -> impl Iterator<Item = io::Result<String>> {
+        options.lines(self.lines())
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn paragraphs(self) -> io::Result<Vec<Vec<String>>> {
+        crate::split_paragraphs(self.lines())
+    }
+
+    pub fn parsed_lines<T>(self) -> impl Iterator<Item = Result<T, ParseError<T::Err>>>
+    where T: FromStr, {
+        crate::parse_lines(self.lines())
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn read_to_string(mut self) -> io::Result<String> {
+        let mut text = String::new();
+        self.reader.read_to_string(&mut text)?;
+        Ok(text)
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Self {
+        Self::from_text(text)
+    }
+}
+
+/// Line-level preprocessing options for reading an input file.
+///
+/// `InputReader::new(path).trim().skip_empty().comment_prefix('#').lines()`
+#[derive(Debug, Clone)]
+pub struct InputReader {
+    path: PathBuf,
+    trim: bool,
+    skip_empty: bool,
+    comment_prefix: Option<char>,
+}
+
+impl InputReader {
+    pub fn new<P>(path: P) -> Self
+    where P: AsRef<Path>, {
+        Self { path: path.as_ref().to_path_buf(), trim: false, skip_empty: false, comment_prefix: None }
+    }
+
+    /// Strips leading and trailing whitespace from every line.
+    #[must_use]
+    pub const fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+
+    /// Drops lines that are empty (after trimming, if enabled).
+    #[must_use]
+    pub const fn skip_empty(mut self) -> Self {
+        self.skip_empty = true;
+        self
+    }
+
+    /// Drops lines whose first non-whitespace character is `prefix`.
+    #[must_use]
+    pub const fn comment_prefix(mut self, prefix: char) -> Self {
+        self.comment_prefix = Some(prefix);
+        self
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn lines(self) -> Result<impl Iterator<Item = Result<String, InputError>>, InputError> {
+        Ok(crate::read_lines(&self.path)?.filter_map(move |line| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if self.comment_prefix.is_some_and(|prefix| line.trim_start().starts_with(prefix)) {
+                return None;
+            }
+            let line = if self.trim { line.trim().to_owned() } else { line };
+            if self.skip_empty && line.is_empty() {
+                return None;
+            }
+            Some(Ok(line))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::input::Input;
+
+    #[test]
+    fn lines_from_text() {
+        let lines: Vec<_> = Input::from_text("L68\nR48\n").lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["L68", "R48"]);
+    }
+
+    #[test]
+    fn paragraphs_from_text() {
+        assert_eq!(Input::from("1\n2\n\n3").paragraphs().unwrap(), vec![vec!["1", "2"], vec!["3"]]);
+    }
+
+    #[test]
+    fn parsed_lines_from_reader() {
+        let reader = std::io::Cursor::new(b"10\n-3\nx\n".to_vec());
+        let parsed: Vec<_> = Input::from_reader(reader).parsed_lines::<i32>().collect();
+        assert_eq!(parsed[0].as_ref().unwrap(), &10);
+        assert_eq!(parsed[1].as_ref().unwrap(), &-3);
+        assert_eq!(parsed[2].as_ref().unwrap_err().line(), 3);
+    }
+
+    #[test]
+    fn read_to_string_returns_everything() {
+        assert_eq!(Input::from_text("a\nb\n").read_to_string().unwrap(), "a\nb\n");
+    }
+
+    #[test]
+    fn normalized_lines_drop_windows_artifacts() {
+        let lines: Vec<_> = Input::from_text("\u{feff}L68\r\nR48\r\n\r\n")
+            .normalized_lines(crate::normalize::Normalize::default())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines, vec!["L68", "R48"]);
+    }
+
+    #[test]
+    fn reader_options_compose() {
+        use crate::input::InputReader;
+
+        let path = crate::test::write_temp("reader.txt", "  L68 \n\n# comment\n   # indented comment\nR48\n   \n");
+        let lines = |reader: InputReader| reader.lines().unwrap().map(Result::unwrap).collect::<Vec<_>>();
+
+        assert_eq!(lines(InputReader::new(&path)).len(), 6);
+        assert_eq!(lines(InputReader::new(&path).trim().skip_empty().comment_prefix('#')), vec!["L68", "R48"]);
+        assert_eq!(lines(InputReader::new(&path).skip_empty().comment_prefix('#')), vec!["  L68 ", "R48", "   "]);
+        assert_eq!(lines(InputReader::new(&path).trim().comment_prefix('#')), vec!["L68", "", "R48", ""]);
+    }
+
+}
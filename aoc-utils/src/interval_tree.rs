@@ -0,0 +1,135 @@
+use std::ops::Range;
+
+/// Half-open `i64` ranges, each with a value, that answer which of them hold a point or meet a
+/// range in `O(log n + k)` for `k` matches.
+///
+/// Built once from all its ranges: they're sorted by start and read as a balanced search tree,
+/// each subtree knowing the furthest end below it so whole subtrees can be skipped.
+#[derive(Debug, Clone)]
+pub struct IntervalTree<V> {
+    entries: Vec<(Range<i64>, V)>,
+    /// The largest end in the subtree rooted at each index.
+    max_end: Vec<i64>,
+}
+
+impl<V> IntervalTree<V> {
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Every range and value, by increasing start.
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<i64>, &V)> {
+        self.entries.iter().map(|(range, value)| (range, value))
+    }
+
+    /// The ranges holding `point`, by increasing start.
+    #[must_use]
+    pub fn containing(&self, point: i64) -> Vec<(&Range<i64>, &V)> {
+        // No half-open `i64` range can hold `i64::MAX`, so saturating loses nothing.
+        self.overlapping(point..point.saturating_add(1))
+    }
+
+    /// The ranges sharing at least one value with `query`, by increasing start.
+    #[must_use]
+    pub fn overlapping(&self, query: Range<i64>) -> Vec<(&Range<i64>, &V)> {
+        let mut found = Vec::new();
+        if !query.is_empty() {
+            self.search(0, self.entries.len(), &query, &mut found);
+        }
+        found
+    }
+
+    fn search<'a>(&'a self, lo: usize, hi: usize, query: &Range<i64>, found: &mut Vec<(&'a Range<i64>, &'a V)>) {
+        if lo == hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] <= query.start {
+            return;
+        }
+        self.search(lo, mid, query, found);
+        let (range, value) = &self.entries[mid];
+        // Everything to the right starts at or after `range`.
+        if range.start >= query.end {
+            return;
+        }
+        if range.end > query.start {
+            found.push((range, value));
+        }
+        self.search(mid + 1, hi, query, found);
+    }
+
+    /// Fills in `max_end` for the subtree over `lo..hi`, returning its largest end.
+    fn index(&mut self, lo: usize, hi: usize) -> i64 {
+        if lo == hi {
+            return i64::MIN;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let end = self.entries[mid].0.end.max(self.index(lo, mid)).max(self.index(mid + 1, hi));
+        self.max_end[mid] = end;
+        end
+    }
+}
+
+impl<V> FromIterator<(Range<i64>, V)> for IntervalTree<V> {
+    fn from_iter<I: IntoIterator<Item = (Range<i64>, V)>>(iter: I) -> Self {
+        let mut entries: Vec<(Range<i64>, V)> = iter.into_iter().collect();
+        entries.sort_by_key(|(range, _)| range.start);
+        let mut tree = Self { max_end: vec![i64::MIN; entries.len()], entries };
+        tree.index(0, tree.entries.len());
+        tree
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::ops::Range;
+
+    use crate::interval_tree::IntervalTree;
+
+    #[test]
+    fn stabbing_finds_every_holder() {
+        let shifts: IntervalTree<&str> = [(9..17, "day"), (0..8, "night"), (16..24, "late"), (12..13, "lunch")].into_iter().collect();
+        assert_eq!(shifts.len(), 4);
+        let names = |point| shifts.containing(point).into_iter().map(|(_, name)| *name).collect::<Vec<_>>();
+        assert_eq!(names(12), ["day", "lunch"]);
+        assert_eq!(names(16), ["day", "late"]);
+        assert_eq!(names(8), Vec::<&str>::new());
+        assert_eq!(shifts.overlapping(7..10).len(), 2);
+        assert!(shifts.overlapping(5..5).is_empty());
+        assert!(IntervalTree::<()>::from_iter([]).containing(0).is_empty());
+    }
+
+    #[test]
+    fn queries_match_a_linear_scan() {
+        let mut seed = 12_345_u64;
+        let mut next = |bound: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 33) % bound
+        };
+        let ranges: Vec<Range<i64>> = (0..500)
+            .map(|_| {
+                let (start, len) = (next(1000).cast_signed(), next(50).cast_signed());
+                start..start + len
+            })
+            .collect();
+        let tree: IntervalTree<usize> = ranges.iter().cloned().zip(0..).collect();
+        for _ in 0..200 {
+            let start = next(1100).cast_signed() - 50;
+            let query = start..start + next(30).cast_signed();
+            let mut expected: Vec<usize> = (0..ranges.len()).filter(|&i| ranges[i].start < query.end && ranges[i].end > query.start && !query.is_empty()).collect();
+            let mut found: Vec<usize> = tree.overlapping(query.clone()).into_iter().map(|(_, &i)| i).collect();
+            expected.sort_unstable();
+            found.sort_unstable();
+            assert_eq!(found, expected, "query {query:?}");
+        }
+    }
+
+}
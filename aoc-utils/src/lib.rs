@@ -0,0 +1,627 @@
+//! Input reading, parsing and grid utilities for Advent of Code solutions, independent of any
+//! particular year's puzzles.
+
+#[cfg(feature = "clipboard")]
+mod clipboard;
+mod decompress;
+mod error;
+pub mod fenwick;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+#[cfg(feature = "http")]
+mod http;
+pub mod input;
+pub mod interval_tree;
+pub mod math;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod normalize;
+pub mod progress_bar;
+pub mod range_map;
+pub mod range_set;
+pub mod search;
+pub mod segment_tree;
+pub mod simulate;
+pub mod sparse_grid;
+pub mod union_find;
+
+#[cfg(feature = "clipboard")]
+pub use clipboard::read_clipboard_lines;
+#[cfg(feature = "http")]
+pub use http::read_lines_url;
+#[cfg(feature = "mmap")]
+pub use mmap::read_lines_mmap;
+
+pub use error::InputError;
+pub use geometry::Direction;
+
+use std::{fmt, fs::File, io::{self, BufRead, Read}, ops::Range, path::{Path, PathBuf}, str::FromStr};
+
+/// The lines of an input file (or URL), with read failures tagged by path and line number.
+pub struct Lines {
+    path: PathBuf,
+    line: usize,
+    inner: io::Lines<Box<dyn BufRead>>,
+}
+
+impl Lines {
+    fn new<P>(path: P, reader: Box<dyn BufRead>) -> Self
+    where P: AsRef<Path>, {
+        Self { path: path.as_ref().to_path_buf(), line: 0, inner: reader.lines() }
+    }
+}
+
+impl Iterator for Lines {
+    type Item = Result<String, InputError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.inner.next()?;
+        self.line += 1;
+        Some(line.map_err(|e| InputError::new(&self.path, e).at_line(self.line)))
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_lines<P>(filename: P) -> Result<Lines, InputError>
+where P: AsRef<Path>, {
+    Ok(Lines::new(&filename, open(&filename)?))
+}
+
+/// Opens `filename` for buffered reading, decompressing `.gz` and `.zst` files on the fly.
+#[allow(clippy::missing_errors_doc)]
+pub fn open<P>(filename: P) -> Result<Box<dyn BufRead>, InputError>
+where P: AsRef<Path>, {
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|e| InputError::new(path, e))?;
+    let reader: io::Result<Box<dyn BufRead>> = match path.extension().and_then(std::ffi::OsStr::to_str) {
+        Some("gz") => decompress::gzip(file),
+        Some("zst") => decompress::zstd(file),
+        _ => Ok(Box::new(io::BufReader::new(file))),
+    };
+    reader.map_err(|e| InputError::new(path, e))
+}
+
+/// Reads lines from `filename`, or from locked stdin when it is `-` or absent.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_lines_or_stdin<P>(filename: Option<P>) -> Result<Lines, InputError>
+where P: AsRef<Path>, {
+    match filename {
+        Some(filename) if filename.as_ref() != Path::new("-") => read_lines(filename),
+        _ => Ok(Lines::new("-", Box::new(io::stdin().lock()))),
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_to_string<P>(filename: P) -> Result<String, InputError>
+where P: AsRef<Path>, {
+    let mut text = String::new();
+    open(&filename)?.read_to_string(&mut text).map_err(|e| InputError::new(filename, e))?;
+    Ok(text)
+}
+
+/// Reads all of `filename`, or of stdin when it is `-` or absent.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_to_string_or_stdin<P>(filename: Option<P>) -> Result<String, InputError>
+where P: AsRef<Path>, {
+    match filename {
+        Some(filename) if filename.as_ref() != Path::new("-") => read_to_string(filename),
+        _ => {
+            let mut text = String::new();
+            io::stdin().lock().read_to_string(&mut text).map_err(|e| InputError::new("-", e))?;
+            Ok(text)
+        }
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_bytes<P>(filename: P) -> Result<Vec<u8>, InputError>
+where P: AsRef<Path>, {
+    let mut bytes = Vec::new();
+    open(&filename)?.read_to_end(&mut bytes).map_err(|e| InputError::new(filename, e))?;
+    Ok(bytes)
+}
+
+/// Iterates the bytes of an input with line endings (`\n` and `\r`) skipped.
+pub fn non_newline_bytes(bytes: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    bytes.iter().copied().filter(|&b| b != b'\n' && b != b'\r')
+}
+
+/// A failure to read or parse a single line, tagged with its 1-based line number.
+#[derive(Debug)]
+pub enum ParseError<E> {
+    Io { line: usize, source: io::Error },
+    Parse { line: usize, source: E },
+}
+
+impl<E> ParseError<E> {
+    pub const fn line(&self) -> usize {
+        match self {
+            Self::Io { line, .. } | Self::Parse { line, .. } => *line,
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { line, source } => write!(f, "failed to read line {line}: {source}"),
+            Self::Parse { line, source } => write!(f, "failed to parse line {line}: {source:?}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> std::error::Error for ParseError<E> {}
+
+impl<E: fmt::Debug> From<ParseError<E>> for io::Error {
+    fn from(value: ParseError<E>) -> Self {
+        let kind = match &value {
+            ParseError::Io { source, .. } => source.kind(),
+            ParseError::Parse { .. } => io::ErrorKind::InvalidData,
+        };
+        Self::new(kind, value.to_string())
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_parsed_lines<T, P>(filename: P) -> Result<impl Iterator<Item = Result<T, ParseError<T::Err>>>, InputError>
+where T: FromStr, P: AsRef<Path>, {
+    Ok(parse_lines(read_lines(filename)?))
+}
+
+fn parse_lines<T, I, E>(lines: I) -> impl Iterator<Item = Result<T, ParseError<T::Err>>>
+where T: FromStr, I: IntoIterator<Item = Result<String, E>>, io::Error: From<E>, {
+    lines.into_iter().enumerate().map(|(i, line)| {
+        let line_number = i + 1;
+        line.map_err(|source| ParseError::Io { line: line_number, source: source.into() })?
+            .parse()
+            .map_err(|source| ParseError::Parse { line: line_number, source })
+    })
+}
+
+/// Parses every whitespace-separated token in the file, tagging failures with their line.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_tokens<T, P>(filename: P) -> Result<impl Iterator<Item = Result<T, ParseError<T::Err>>>, InputError>
+where T: FromStr, P: AsRef<Path>, {
+    Ok(read_lines(filename)?.enumerate().flat_map(|(i, line)| {
+        let line_number = i + 1;
+        match line {
+            Ok(line) => line
+                .split_whitespace()
+                .map(|token| token.parse().map_err(|source| ParseError::Parse { line: line_number, source }))
+                .collect::<Vec<_>>(),
+            Err(source) => vec![Err(ParseError::Io { line: line_number, source: source.into() })],
+        }
+    }))
+}
+
+/// Extracts every signed integer from `line`, e.g. `"Game 13: -4 red"` yields `13, -4`.
+///
+/// A `-` only counts as a sign when it directly precedes a digit and does not follow one,
+/// so ranges like `"1-3"` yield `1, 3`. Values that overflow `i64` are skipped.
+pub fn ints(line: &str) -> impl Iterator<Item = i64> + '_ {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos < bytes.len() {
+            let start = pos;
+            let negative = bytes[pos] == b'-'
+                && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit)
+                && (pos == 0 || !bytes[pos - 1].is_ascii_digit());
+            if negative {
+                pos += 1;
+            }
+            if bytes[pos].is_ascii_digit() {
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+                if let Ok(value) = line[start..pos].parse() {
+                    return Some(value);
+                }
+            } else {
+                pos += 1;
+            }
+        }
+        None
+    })
+}
+
+/// A field that failed to parse, with its 0-based index within the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError<E> {
+    pub index: usize,
+    pub source: E,
+}
+
+impl<E: fmt::Debug> fmt::Display for FieldError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse field {}: {:?}", self.index, self.source)
+    }
+}
+
+impl<E: fmt::Debug> std::error::Error for FieldError<E> {}
+
+/// Splits `s` on `sep` and parses each (whitespace-trimmed) field.
+#[allow(clippy::missing_errors_doc)]
+pub fn split_parse<T>(s: &str, sep: char) -> Result<Vec<T>, FieldError<T::Err>>
+where T: FromStr, {
+    s.split(sep)
+        .enumerate()
+        .map(|(index, field)| field.trim().parse().map_err(|source| FieldError { index, source }))
+        .collect()
+}
+
+#[allow(clippy::missing_errors_doc, clippy::type_complexity)]
+pub fn read_split_lines<T, P>(filename: P, sep: char) -> Result<impl Iterator<Item = Result<Vec<T>, ParseError<FieldError<T::Err>>>>, InputError>
+where T: FromStr, P: AsRef<Path>, {
+    Ok(read_lines(filename)?.enumerate().map(move |(i, line)| {
+        let line_number = i + 1;
+        let line = line.map_err(|source| ParseError::Io { line: line_number, source: source.into() })?;
+        split_parse(&line, sep).map_err(|source| ParseError::Parse { line: line_number, source })
+    }))
+}
+
+/// Slices `line` at the byte ranges in `columns` and parses each trimmed field.
+///
+/// Fields that are blank, or lie partly or wholly past the end of a short line,
+/// are clamped to what is there and yield `None` when nothing is left.
+#[allow(clippy::missing_errors_doc)]
+pub fn fixed_width<T>(line: &str, columns: &[Range<usize>]) -> Result<Vec<Option<T>>, FieldError<T::Err>>
+where T: FromStr, {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, range)| {
+            let start = range.start.min(line.len());
+            let end = range.end.clamp(start, line.len());
+            match line.get(start..end).map(str::trim) {
+                None | Some("") => Ok(None),
+                Some(field) => field.parse().map(Some).map_err(|source| FieldError { index, source }),
+            }
+        })
+        .collect()
+}
+
+#[allow(clippy::missing_errors_doc, clippy::type_complexity)]
+pub fn read_fixed_width<T, P>(filename: P, columns: &[Range<usize>]) -> Result<impl Iterator<Item = Result<Vec<Option<T>>, ParseError<FieldError<T::Err>>>>, InputError>
+where T: FromStr, P: AsRef<Path>, {
+    Ok(read_lines(filename)?.enumerate().map(move |(i, line)| {
+        let line_number = i + 1;
+        let line = line.map_err(|source| ParseError::Io { line: line_number, source: source.into() })?;
+        fixed_width(&line, columns).map_err(|source| ParseError::Parse { line: line_number, source })
+    }))
+}
+
+/// Groups lines into records of exactly `n` lines, failing on a short final record.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_line_chunks<P>(filename: P, n: usize) -> Result<impl Iterator<Item = Result<Vec<String>, InputError>>, InputError>
+where P: AsRef<Path>, {
+    if n == 0 {
+        return Err(InputError::new(filename, io::Error::new(io::ErrorKind::InvalidInput, "chunk size must be non-zero")));
+    }
+    let mut lines = read_lines(filename)?;
+    let mut read = 0;
+    Ok(std::iter::from_fn(move || {
+        let mut chunk = Vec::with_capacity(n);
+        for line in lines.by_ref().take(n) {
+            match line {
+                Ok(line) => chunk.push(line),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        read += chunk.len();
+        match chunk.len() {
+            0 => None,
+            len if len == n => Some(Ok(chunk)),
+            _ => Some(Err(InputError::new(
+                &lines.path,
+                io::Error::new(io::ErrorKind::InvalidData, format!("file has {read} lines, which is not a multiple of {n}")),
+            ))),
+        }
+    }))
+}
+
+/// How `transpose_lines` treats lines shorter than the longest one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ragged {
+    Pad(char),
+    Reject,
+}
+
+/// Turns columns into rows, so `["ab", "cd"]` becomes `["ac", "bd"]`.
+#[allow(clippy::missing_errors_doc)]
+pub fn transpose_lines<S>(lines: &[S], ragged: Ragged) -> Result<Vec<String>, grid::RaggedRowError>
+where S: AsRef<str>, {
+    let rows: Vec<Vec<char>> = lines.iter().map(|line| line.as_ref().chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let pad = match ragged {
+        Ragged::Pad(pad) => pad,
+        Ragged::Reject => {
+            if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+                return Err(grid::RaggedRowError { line: i + 1, width: row.len(), expected: width });
+            }
+            ' '
+        }
+    };
+    Ok((0..width).map(|x| rows.iter().map(|row| row.get(x).copied().unwrap_or(pad)).collect()).collect())
+}
+
+/// Reads a two-column file such as `3   4` per line into its left and right columns.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_columns<T, P>(filename: P) -> Result<(Vec<T>, Vec<T>), InputError>
+where T: FromStr, T::Err: fmt::Debug, P: AsRef<Path>, {
+    let mut columns = read_n_columns(filename, 2)?.into_iter();
+    match (columns.next(), columns.next()) {
+        (Some(left), Some(right)) => Ok((left, right)),
+        _ => unreachable!("read_n_columns returns exactly the requested number of columns"),
+    }
+}
+
+/// Reads `n` whitespace-separated columns, returning them column-major.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_n_columns<T, P>(filename: P, n: usize) -> Result<Vec<Vec<T>>, InputError>
+where T: FromStr, T::Err: fmt::Debug, P: AsRef<Path>, {
+    let mut columns: Vec<Vec<T>> = std::iter::repeat_with(Vec::new).take(n).collect();
+    let invalid = |line: usize, message: String| {
+        InputError::new(&filename, io::Error::new(io::ErrorKind::InvalidData, message)).at_line(line)
+    };
+    for (i, line) in read_lines(&filename)?.enumerate() {
+        let line_number = i + 1;
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != n {
+            return Err(invalid(line_number, format!("found {} columns, expected {n}", fields.len())));
+        }
+        for (column, field) in columns.iter_mut().zip(fields) {
+            column.push(field.parse().map_err(|e| invalid(line_number, format!("failed to parse {field:?}: {e:?}")))?);
+        }
+    }
+    Ok(columns)
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_grid<P>(filename: P) -> Result<Vec<Vec<u8>>, InputError>
+where P: AsRef<Path>, {
+    read_rows(filename, String::into_bytes)
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn read_char_grid<P>(filename: P) -> Result<Vec<Vec<char>>, InputError>
+where P: AsRef<Path>, {
+    read_rows(filename, |line| line.chars().collect())
+}
+
+/// Reads one row per line, rejecting any row whose width differs from the first.
+fn read_rows<T, P, F>(filename: P, to_row: F) -> Result<Vec<Vec<T>>, InputError>
+where P: AsRef<Path>, F: Fn(String) -> Vec<T>, {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (i, line) in read_lines(&filename)?.enumerate() {
+        let row = to_row(line?);
+        if let Some(first) = rows.first()
+            && first.len() != row.len()
+        {
+            let error = grid::RaggedRowError { line: i + 1, width: row.len(), expected: first.len() };
+            return Err(InputError::new(filename, error.into()).at_line(i + 1));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Groups lines into blocks separated by one or more blank lines.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_paragraphs<P>(filename: P) -> Result<Vec<Vec<String>>, InputError>
+where P: AsRef<Path>, {
+    split_paragraphs(read_lines(filename)?)
+}
+
+fn split_paragraphs<I, E>(lines: I) -> Result<Vec<Vec<String>>, E>
+where I: IntoIterator<Item = Result<String, E>>, {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    for line in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    Ok(paragraphs)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    pub fn write_temp(name: &str, contents: impl AsRef<[u8]>) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-utils-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn read_to_string_reads_whole_file() {
+        let path = write_temp("whole.txt", "L50\nR20\n");
+        assert_eq!(crate::read_to_string(&path).unwrap(), "L50\nR20\n");
+    }
+
+    #[test]
+    fn parsed_lines_carry_line_numbers() {
+        let path = write_temp("parsed.txt", "1\n2\nthree\n4\n");
+        let parsed: Vec<_> = crate::read_parsed_lines::<u32, _>(&path).unwrap().collect();
+
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[0].as_ref().unwrap(), &1);
+        assert_eq!(parsed[1].as_ref().unwrap(), &2);
+        assert!(matches!(parsed[2], Err(crate::ParseError::Parse { line: 3, .. })));
+        assert_eq!(parsed[3].as_ref().unwrap(), &4);
+    }
+
+    #[test]
+    fn grid_rows_are_read() {
+        let path = write_temp("grid.txt", "#.#\n.S.\n");
+        assert_eq!(crate::read_grid(&path).unwrap(), vec![b"#.#".to_vec(), b".S.".to_vec()]);
+        assert_eq!(crate::read_char_grid(&path).unwrap()[1], vec!['.', 'S', '.']);
+    }
+
+    #[test]
+    fn ragged_grid_reports_line() {
+        let path = write_temp("ragged.txt", "###\n###\n##\n");
+        let error = crate::read_grid(&path).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(error.line, Some(3));
+        assert!(error.to_string().contains("line 3 has width 2"));
+    }
+
+    #[test]
+    fn paragraphs_split_on_blank_lines() {
+        let path = write_temp("paragraphs.txt", "a\nb\n\n\nc\n\nd\ne\n\n");
+        assert_eq!(
+            crate::read_paragraphs(&path).unwrap(),
+            vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]]
+        );
+    }
+
+    #[test]
+    fn named_file_is_read_instead_of_stdin() {
+        let path = write_temp("or_stdin.txt", "R5\nL5\n");
+        let lines: Vec<_> = crate::read_lines_or_stdin(Some(&path)).unwrap().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["R5", "L5"]);
+    }
+
+    #[test]
+    fn tokens_span_lines() {
+        let path = write_temp("tokens.txt", "1 2\t3\n\n  4   5\n6 x\n");
+        let tokens: Vec<_> = crate::read_tokens::<u8, _>(&path).unwrap().collect();
+        assert_eq!(tokens.len(), 7);
+        assert_eq!(tokens[..6].iter().map(|t| *t.as_ref().unwrap()).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(tokens[6].as_ref().unwrap_err().line(), 4);
+    }
+
+    #[test]
+    fn ints_from_prose() {
+        assert_eq!(crate::ints("Game 13: 4 red, 5 blue").collect::<Vec<_>>(), vec![13, 4, 5]);
+        assert_eq!(crate::ints("no numbers here").count(), 0);
+        assert_eq!(crate::ints("").count(), 0);
+    }
+
+    #[test]
+    fn ints_handle_negatives() {
+        assert_eq!(crate::ints("x=-3, y=-12").collect::<Vec<_>>(), vec![-3, -12]);
+        assert_eq!(crate::ints("-7").collect::<Vec<_>>(), vec![-7]);
+        assert_eq!(crate::ints("p=0,4 v=3,-3").collect::<Vec<_>>(), vec![0, 4, 3, -3]);
+        assert_eq!(crate::ints("a - b --2").collect::<Vec<_>>(), vec![-2]);
+        assert_eq!(crate::ints("trailing -").count(), 0);
+    }
+
+    #[test]
+    fn ints_respect_digit_boundaries() {
+        assert_eq!(crate::ints("1-3 a: abcde").collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(crate::ints("x1y22z333").collect::<Vec<_>>(), vec![1, 22, 333]);
+        assert_eq!(crate::ints("007").collect::<Vec<_>>(), vec![7]);
+        assert_eq!(crate::ints("R1220").collect::<Vec<_>>(), vec![1220]);
+    }
+
+    #[test]
+    fn ints_skip_overflow() {
+        assert_eq!(crate::ints("99999999999999999999 9223372036854775807").collect::<Vec<_>>(), vec![i64::MAX]);
+        assert_eq!(crate::ints("-9223372036854775808").collect::<Vec<_>>(), vec![i64::MIN]);
+    }
+
+    #[test]
+    fn split_parse_names_bad_field() {
+        assert_eq!(crate::split_parse::<u32>("3,4, 5", ','), Ok(vec![3, 4, 5]));
+        let error = crate::split_parse::<u32>("3;x;5", ';').unwrap_err();
+        assert_eq!(error.index, 1);
+        assert!(error.to_string().contains("field 1"));
+    }
+
+    #[test]
+    fn split_lines_carry_line_and_field() {
+        let path = write_temp("split.txt", "1,2\n3,4,5\n6,,7\n");
+        let rows: Vec<_> = crate::read_split_lines::<u8, _>(&path, ',').unwrap().collect();
+        assert_eq!(rows[0].as_ref().unwrap(), &vec![1, 2]);
+        assert_eq!(rows[1].as_ref().unwrap(), &vec![3, 4, 5]);
+        assert!(matches!(rows[2], Err(crate::ParseError::Parse { line: 3, source: crate::FieldError { index: 1, .. } })));
+    }
+
+    #[test]
+    fn two_columns_are_split() {
+        let path = write_temp("columns.txt", "3   4\n4   3\n2   5\n");
+        assert_eq!(crate::read_columns::<u32, _>(&path).unwrap(), (vec![3, 4, 2], vec![4, 3, 5]));
+    }
+
+    #[test]
+    fn n_columns_validate_count_and_values() {
+        let path = write_temp("columns3.txt", "1 2 3\n4 5 6\n");
+        assert_eq!(crate::read_n_columns::<i8, _>(&path, 3).unwrap(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        let path = write_temp("columns-short.txt", "1 2 3\n4 5\n");
+        assert_eq!(crate::read_n_columns::<i8, _>(&path, 3).unwrap_err().line, Some(2));
+
+        let path = write_temp("columns-bad.txt", "1 2\nx 5\n");
+        let error = crate::read_columns::<i8, _>(&path).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn fixed_width_handles_ragged_lines() {
+        let columns = [1..2, 5..6, 9..10];
+        assert_eq!(crate::fixed_width::<char>("[Z] [M] [P]", &columns), Ok(vec![Some('Z'), Some('M'), Some('P')]));
+        assert_eq!(crate::fixed_width::<char>("    [D]", &columns), Ok(vec![None, Some('D'), None]));
+        assert_eq!(crate::fixed_width::<char>("", &columns), Ok(vec![None, None, None]));
+    }
+
+    #[test]
+    fn fixed_width_reports_bad_field() {
+        let error = crate::fixed_width::<u8>("12  ab  34", &[0..2, 4..6, 8..10]).unwrap_err();
+        assert_eq!(error.index, 1);
+
+        let path = write_temp("fixed.txt", "  1 20\n300   \n");
+        let rows: Vec<_> = crate::read_fixed_width::<u16, _>(&path, &[0..3, 3..6]).unwrap().map(Result::unwrap).collect();
+        assert_eq!(rows, vec![vec![Some(1), Some(20)], vec![Some(300), None]]);
+    }
+
+    #[test]
+    fn line_chunks_group_records() {
+        let path = write_temp("chunks.txt", "a\nb\nc\nd\ne\nf\n");
+        let chunks: Vec<_> = crate::read_line_chunks(&path, 3).unwrap().map(Result::unwrap).collect();
+        assert_eq!(chunks, vec![vec!["a", "b", "c"], vec!["d", "e", "f"]]);
+    }
+
+    #[test]
+    fn line_chunks_reject_partial_record() {
+        let path = write_temp("chunks-short.txt", "a\nb\nc\nd\n");
+        let chunks: Vec<_> = crate::read_line_chunks(&path, 3).unwrap().collect();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[1].as_ref().unwrap_err().to_string().contains("4 lines"));
+        assert!(crate::read_line_chunks(&path, 0).is_err());
+    }
+
+    #[test]
+    fn transpose_pads_or_rejects_ragged_lines() {
+        use crate::Ragged;
+
+        assert_eq!(crate::transpose_lines(&["abc", "def"], Ragged::Reject).unwrap(), vec!["ad", "be", "cf"]);
+        assert_eq!(crate::transpose_lines(&["123", "4", "56"], Ragged::Pad(' ')).unwrap(), vec!["145", "2 6", "3  "]);
+        assert_eq!(crate::transpose_lines(&["123", "4"], Ragged::Reject).unwrap_err().line, 2);
+        assert!(crate::transpose_lines::<String>(&[], Ragged::Reject).unwrap().is_empty());
+    }
+
+    #[test]
+    fn bytes_skip_newlines() {
+        let path = write_temp("bytes.txt", "ab\r\ncd\n");
+        let bytes = crate::read_bytes(&path).unwrap();
+        assert_eq!(bytes, b"ab\r\ncd\n");
+        assert_eq!(crate::non_newline_bytes(&bytes).collect::<Vec<_>>(), b"abcd");
+    }
+
+}
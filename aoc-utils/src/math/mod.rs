@@ -0,0 +1,249 @@
+mod mod_int;
+mod ratio;
+mod wide;
+
+pub use mod_int::ModInt;
+pub use ratio::Ratio;
+pub use wide::{OutOfRangeError, mul_wide, mul_wide_signed, sum_wide, to_u64};
+#[cfg(feature = "bigint")]
+pub use wide::{BigInt, BigUint};
+
+use std::{fmt, hash::Hash, ops::{Add, Div, Mul, Neg, Rem, Sub}};
+
+/// The integer types the number-theory helpers work over.
+pub trait Integer:
+    Copy + Ord + Hash + fmt::Debug + fmt::Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The greatest common divisor, never negative; `gcd(0, 0)` is 0.
+    ///
+    /// # Panics
+    /// For signed types, if the result is `2^(bits - 1)`, which only fits the unsigned type.
+    #[must_use]
+    fn gcd(self, other: Self) -> Self;
+
+    /// The least common multiple, never negative, or `None` if it overflows.
+    #[must_use]
+    fn lcm(self, other: Self) -> Option<Self>;
+}
+
+/// Signed integers, which can express Bézout coefficients.
+pub trait SignedInteger: Integer + Neg<Output = Self> {
+    /// `(g, x, y)` with `g = gcd(self, other)` and `self * x + other * y = g`.
+    #[must_use]
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self);
+}
+
+macro_rules! unsigned_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+
+            fn lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                (self / self.gcd(other)).checked_mul(other)
+            }
+        }
+    )*};
+}
+
+macro_rules! signed_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn gcd(self, other: Self) -> Self {
+                let (mut a, mut b) = (self, other);
+                while b != 0 {
+                    (a, b) = (b, a.wrapping_rem(b));
+                }
+                a.abs()
+            }
+
+            fn lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                (self / self.gcd(other)).checked_mul(other)?.checked_abs()
+            }
+        }
+
+        impl SignedInteger for $t {
+            fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+                let (mut old_r, mut r) = (self, other);
+                let (mut old_x, mut x) = (1, 0);
+                let (mut old_y, mut y) = (0, 1);
+                while r != 0 {
+                    let quotient = old_r / r;
+                    (old_r, r) = (r, old_r - quotient * r);
+                    (old_x, x) = (x, old_x - quotient * x);
+                    (old_y, y) = (y, old_y - quotient * y);
+                }
+                if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+            }
+        }
+    )*};
+}
+
+unsigned_integer!(u8, u16, u32, u64, u128, usize);
+signed_integer!(i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor of `a` and `b`; see [`Integer::gcd`].
+#[must_use]
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(b)
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it overflows.
+#[must_use]
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    a.lcm(b)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`, by the extended Euclidean algorithm.
+#[must_use]
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    a.extended_gcd(b)
+}
+
+/// The `x` meeting every `x ≡ residue (mod modulus)` in `residues`, by the Chinese remainder
+/// theorem, as `(x, m)`: every solution is `x` plus a multiple of `m`, with `0 <= x < m`.
+///
+/// Moduli need not be coprime. `None` if the congruences conflict, or `m` overflows `i64`.
+///
+/// # Panics
+/// If a modulus isn't positive.
+#[must_use]
+pub fn crt(residues: &[(i64, i64)]) -> Option<(i64, i64)> {
+    assert!(residues.iter().all(|&(_, modulus)| modulus > 0), "Moduli must be positive.");
+    let (x, m) = residues.iter().try_fold((0, 1), |combined, &(r, m)| congruence(combined, (i128::from(r), i128::from(m))))?;
+    Some((i64::try_from(x).ok()?, i64::try_from(m).ok()?))
+}
+
+/// Merges two congruences `x ≡ r (mod m)` into one, or `None` if they conflict or the combined
+/// modulus overflows `i128`.
+pub(crate) fn congruence((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let (g, inverse, _) = extended_gcd(m1, m2);
+    if (r2 - r1) % g != 0 {
+        return None;
+    }
+    let step = m2 / g;
+    let modulus = m1.checked_mul(step)?;
+    let k = ((r2 - r1) / g % step).checked_mul(inverse)?.rem_euclid(step);
+    Some(((r1 + m1 * k).rem_euclid(modulus), modulus))
+}
+
+/// The one solution of the square system `coefficients * x = constants`, exactly, or `None` if
+/// there isn't exactly one.
+///
+/// Uses fraction-free (Bareiss) elimination, so everything stays an integer until the final
+/// back-substitution; [`Ratio::to_integer`] tells whether a solution is whole, as button presses
+/// must be.
+///
+/// # Panics
+/// If `coefficients` isn't square with a row per constant, or an intermediate value overflows.
+#[must_use]
+pub fn solve_linear(coefficients: &[Vec<i128>], constants: &[i128]) -> Option<Vec<Ratio<i128>>> {
+    let n = constants.len();
+    assert!(coefficients.len() == n && coefficients.iter().all(|row| row.len() == n), "The system must be square.");
+    let mut rows: Vec<Vec<i128>> = coefficients.iter().zip(constants).map(|(row, &c)| row.iter().copied().chain([c]).collect()).collect();
+
+    let mut previous = 1;
+    for k in 0..n {
+        let pivot = (k..n).find(|&i| rows[i][k] != 0)?;
+        rows.swap(k, pivot);
+        let (upper, lower) = rows.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower {
+            let factor = row[k];
+            for j in k..=n {
+                // Bareiss's identity guarantees this division is exact.
+                row[j] = (row[j] * pivot_row[k] - factor * pivot_row[j]) / previous;
+            }
+        }
+        previous = rows[k][k];
+    }
+
+    let mut solution = vec![Ratio::from(0); n];
+    for i in (0..n).rev() {
+        let known = (i + 1..n).fold(Ratio::from(rows[i][n]), |rest, j| rest - Ratio::from(rows[i][j]) * solution[j]);
+        solution[i] = known / Ratio::from(rows[i][i]);
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::math::{Ratio, crt, extended_gcd, gcd, lcm, solve_linear};
+
+    #[test]
+    fn gcd_and_lcm_across_types() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_i32, -7), 7);
+        assert_eq!(gcd(0_u8, 0), 0);
+        assert_eq!(lcm(4_u64, 6), Some(12));
+        assert_eq!(lcm(-4_i128, 6), Some(12));
+        assert_eq!(lcm(0_usize, 5), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(1_u64 << 32, 3 << 32), Some(3 << 32));
+    }
+
+    #[test]
+    fn extended_gcd_gives_bezout_coefficients() {
+        for (a, b) in [(240_i64, 46), (-240, 46), (17, 0), (0, -5), (35, 64)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(extended_gcd(240_i128, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn crt_solves_congruences() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(3, 10)]), Some((3, 10)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0, i64::MAX), (1, i64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn linear_systems_solve_exactly() {
+        // A claw machine: 94a + 22b = 8400, 34a + 67b = 5400.
+        let presses = solve_linear(&[vec![94, 22], vec![34, 67]], &[8400, 5400]).unwrap();
+        assert_eq!(presses.iter().map(Ratio::to_integer).collect::<Vec<_>>(), vec![Some(80), Some(40)]);
+        // One with no whole solution.
+        let presses = solve_linear(&[vec![26, 67], vec![66, 21]], &[12748, 12176]).unwrap();
+        assert!(presses.iter().any(|p| !p.is_integer()));
+
+        let solution = solve_linear(&[vec![0, 2, 1], vec![1, 1, 1], vec![2, 1, 3]], &[7, 6, 13]).unwrap();
+        assert_eq!(solution, vec![Ratio::from(1), Ratio::from(2), Ratio::from(3)]);
+        assert_eq!(solve_linear(&[vec![2, 0], vec![0, 3]], &[1, 1]).unwrap(), vec![Ratio::new(1, 2), Ratio::new(1, 3)]);
+    }
+
+    #[test]
+    fn singular_systems_have_no_single_solution() {
+        assert_eq!(solve_linear(&[vec![1, 2], vec![2, 4]], &[3, 6]), None);
+        assert_eq!(solve_linear(&[vec![1, 2], vec![2, 4]], &[3, 7]), None);
+        assert_eq!(solve_linear(&[], &[]), Some(vec![]));
+    }
+
+}
@@ -0,0 +1,173 @@
+use std::{fmt, iter::{Product, Sum}, ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+
+use crate::math::extended_gcd;
+
+/// An integer modulo `M`, always kept in `0..M`, so long chains of arithmetic can't forget a
+/// `% m`. Products go through `u128`, so any `M` up to `u64::MAX` works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    /// `value` reduced modulo `M`.
+    #[must_use]
+    pub const fn new(value: u64) -> Self {
+        const { assert!(M > 0, "The modulus must be positive.") };
+        Self(value % M)
+    }
+
+    /// The representative in `0..M`.
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    /// `self` raised to `exponent`, by repeated squaring.
+    #[must_use]
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let (mut base, mut result) = (self, Self::new(1));
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The `x` with `self * x == 1`, or `None` if `self` shares a factor with `M` (always the
+    /// case for 0).
+    #[must_use]
+    pub fn inverse(self) -> Option<Self> {
+        let (g, x, _) = extended_gcd(i128::from(self.0), i128::from(M));
+        (g == 1).then(|| Self::from(x))
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Negative values wrap around, so `-1` becomes `M - 1`.
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        Self::from(i128::from(value))
+    }
+}
+
+impl<const M: u64> From<i128> for ModInt<M> {
+    fn from(value: i128) -> Self {
+        let reduced = value.rem_euclid(i128::from(M));
+        Self(u64::try_from(reduced).unwrap_or_default())
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, overflowed) = self.0.overflowing_add(rhs.0);
+        Self(if overflowed || sum >= M { sum.wrapping_sub(M) } else { sum })
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(if self.0 >= rhs.0 { self.0 - rhs.0 } else { M - (rhs.0 - self.0) })
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = u128::from(self.0) * u128::from(rhs.0) % u128::from(M);
+        Self(u64::try_from(product).unwrap_or_default())
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::default() - self
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> SubAssign for ModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const M: u64> MulAssign for ModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::math::ModInt;
+
+    type Mod7 = ModInt<7>;
+
+    #[test]
+    fn arithmetic_wraps_around() {
+        let (a, b) = (Mod7::new(5), Mod7::new(4));
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((-a).value(), 2);
+        assert_eq!(Mod7::from(-1_i64).value(), 6);
+        assert_eq!(Mod7::new(100).to_string(), "2");
+        assert_eq!((1..=6).map(Mod7::new).product::<Mod7>().value(), 6);
+        assert_eq!((1..=6).map(Mod7::new).sum::<Mod7>().value(), 0);
+    }
+
+    #[test]
+    fn pow_and_inverse() {
+        type Prime = ModInt<1_000_000_007>;
+        assert_eq!(Prime::new(2).pow(10).value(), 1024);
+        assert_eq!(Prime::new(3).pow(1_000_000_006).value(), 1);
+        let inverse = Prime::new(123_456).inverse().unwrap();
+        assert_eq!((inverse * Prime::new(123_456)).value(), 1);
+        assert_eq!(ModInt::<12>::new(4).inverse(), None);
+        assert_eq!(ModInt::<12>::new(5).inverse(), Some(ModInt::new(5)));
+    }
+
+    #[test]
+    fn huge_moduli_do_not_overflow() {
+        type Big = ModInt<{ u64::MAX - 58 }>;
+        let a = Big::new(u64::MAX - 60);
+        assert_eq!((a + a).value(), u64::MAX - 62);
+        assert_eq!((a * a).value(), 4);
+        assert_eq!((Big::new(1) - a).value(), 3);
+    }
+
+}
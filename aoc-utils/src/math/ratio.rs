@@ -0,0 +1,166 @@
+use std::{cmp::Ordering, fmt, ops::{Add, Div, Mul, Neg, Sub}};
+
+use crate::math::SignedInteger;
+
+/// An exact fraction, always in lowest terms with a positive denominator, for intersections and
+/// slopes that floating point would round wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T = i128> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: SignedInteger> Ratio<T> {
+    /// `numer / denom` in lowest terms.
+    ///
+    /// # Panics
+    /// If `denom` is zero.
+    #[must_use]
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(denom != T::ZERO, "The denominator must not be zero.");
+        let divisor = numer.gcd(denom);
+        let sign = if denom < T::ZERO { -T::ONE } else { T::ONE };
+        Self { numer: sign * numer / divisor, denom: sign * denom / divisor }
+    }
+
+    #[must_use]
+    pub const fn numer(&self) -> T {
+        self.numer
+    }
+
+    /// Always positive.
+    #[must_use]
+    pub const fn denom(&self) -> T {
+        self.denom
+    }
+
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::ONE
+    }
+
+    /// The value as an integer, or `None` if it has a fractional part.
+    #[must_use]
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    /// One over this.
+    ///
+    /// # Panics
+    /// If this is zero.
+    #[must_use]
+    pub fn recip(self) -> Self {
+        Self::new(self.denom, self.numer)
+    }
+}
+
+impl<T: SignedInteger> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Self { numer: value, denom: T::ONE }
+    }
+}
+
+impl<T: SignedInteger> Add for Ratio<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let denom = self.denom / self.denom.gcd(rhs.denom) * rhs.denom;
+        Self::new(self.numer * (denom / self.denom) + rhs.numer * (denom / rhs.denom), denom)
+    }
+}
+
+impl<T: SignedInteger> Sub for Ratio<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: SignedInteger> Mul for Ratio<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancelling crosswise first keeps the intermediate products small.
+        let (a, b) = (self.numer.gcd(rhs.denom), rhs.numer.gcd(self.denom));
+        let (a, b) = (if a == T::ZERO { T::ONE } else { a }, if b == T::ZERO { T::ONE } else { b });
+        Self::new((self.numer / a) * (rhs.numer / b), (self.denom / b) * (rhs.denom / a))
+    }
+}
+
+/// # Panics
+/// When dividing by zero.
+impl<T: SignedInteger> Div for Ratio<T> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl<T: SignedInteger> Neg for Ratio<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self { numer: -self.numer, denom: self.denom }
+    }
+}
+
+impl<T: SignedInteger> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl<T: SignedInteger> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `3/4`, or just `3` for whole numbers.
+impl<T: SignedInteger> fmt::Display for Ratio<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() { write!(f, "{}", self.numer) } else { write!(f, "{}/{}", self.numer, self.denom) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::math::Ratio;
+
+    #[test]
+    fn ratios_are_normalised() {
+        let half = Ratio::new(3, -6);
+        assert_eq!((half.numer(), half.denom()), (-1, 2));
+        assert_eq!(Ratio::new(0_i64, -5), Ratio::from(0));
+        assert_eq!(Ratio::new(10, 5).to_integer(), Some(2));
+        assert_eq!(Ratio::new(10, 4).to_integer(), None);
+        assert_eq!(Ratio::new(10, 4).to_string(), "5/2");
+        assert_eq!(Ratio::new(-8, 4).to_string(), "-2");
+    }
+
+    #[test]
+    fn arithmetic_stays_exact() {
+        let (third, sixth) = (Ratio::new(1, 3), Ratio::new(1, 6));
+        assert_eq!(third + sixth, Ratio::new(1, 2));
+        assert_eq!(sixth - third, Ratio::new(-1, 6));
+        assert_eq!(third * Ratio::new(3, 4), Ratio::new(1, 4));
+        assert_eq!(third / sixth, Ratio::from(2));
+        assert_eq!(Ratio::new(0, 1) * third, Ratio::from(0));
+        assert_eq!((1..=10).map(|n| Ratio::new(1, n)).fold(Ratio::from(0), |a, b| a + b), Ratio::new(7381, 2520));
+    }
+
+    #[test]
+    fn ratios_order_by_value() {
+        let mut values = vec![Ratio::new(1, 2), Ratio::new(-3, 4), Ratio::new(2, 3), Ratio::from(0)];
+        values.sort();
+        assert_eq!(values, vec![Ratio::new(-3, 4), Ratio::from(0), Ratio::new(1, 2), Ratio::new(2, 3)]);
+        assert!(Ratio::new(1_i128, 3) < Ratio::new(333_333_333_333_333_334, 1_000_000_000_000_000_000));
+    }
+
+    #[test]
+    #[should_panic = "denominator must not be zero"]
+    fn zero_denominators_are_refused() {
+        let _ = Ratio::new(1, 0);
+    }
+
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+
+#[cfg(feature = "bigint")]
+pub use num_bigint::{BigInt, BigUint};
+
+/// `a * b` exactly, which always fits in a `u128`.
+#[must_use]
+pub const fn mul_wide(a: u64, b: u64) -> u128 {
+    a as u128 * b as u128
+}
+
+/// `a * b` exactly, which always fits in an `i128`.
+#[must_use]
+pub const fn mul_wide_signed(a: i64, b: i64) -> i128 {
+    a as i128 * b as i128
+}
+
+/// The sum of `values` in an `i128`, which can't overflow for fewer than 2^64 of them.
+pub fn sum_wide(values: impl IntoIterator<Item = i64>) -> i128 {
+    values.into_iter().map(i128::from).sum()
+}
+
+/// `value` as a `u64` answer, or an error if it's negative or too big, so an overflowed
+/// intermediate shows up as a failure rather than a wrong number.
+#[allow(clippy::missing_errors_doc)]
+pub fn to_u64<T>(value: T) -> Result<u64, OutOfRangeError>
+where T: TryInto<u64> + fmt::Display, {
+    let text = value.to_string();
+    value.try_into().map_err(|_| OutOfRangeError { value: text })
+}
+
+/// A value that doesn't fit in a `u64`, written out in full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfRangeError {
+    pub value: String,
+}
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} does not fit in a u64", self.value)
+    }
+}
+
+impl std::error::Error for OutOfRangeError {}
+
+#[cfg(test)]
+mod test {
+
+    use crate::math::{OutOfRangeError, mul_wide, mul_wide_signed, sum_wide, to_u64};
+
+    #[test]
+    fn widening_keeps_every_digit() {
+        assert_eq!(mul_wide(u64::MAX, u64::MAX), u128::from(u64::MAX) * u128::from(u64::MAX));
+        assert_eq!(mul_wide_signed(i64::MIN, i64::MIN), 1 << 126);
+        assert_eq!(mul_wide_signed(i64::MAX, -2), -2 * i128::from(i64::MAX));
+        assert_eq!(sum_wide([i64::MAX, i64::MAX, 2]), 1 << 64);
+    }
+
+    #[test]
+    fn narrowing_is_checked() {
+        assert_eq!(to_u64(42_i128), Ok(42));
+        assert_eq!(to_u64(u128::from(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(to_u64(-1_i64), Err(OutOfRangeError { value: "-1".to_owned() }));
+        assert_eq!(to_u64(1_u128 << 64).unwrap_err().to_string(), "18446744073709551616 does not fit in a u64");
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn big_integers_narrow_too() {
+        use crate::math::BigInt;
+
+        let big = BigInt::from(u64::MAX) * BigInt::from(u64::MAX);
+        assert!(to_u64(big.clone()).is_err());
+        assert_eq!(to_u64(big / BigInt::from(u64::MAX)), Ok(u64::MAX));
+    }
+
+}
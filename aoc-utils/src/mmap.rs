@@ -0,0 +1,66 @@
+use std::{fs::File, io, path::Path};
+
+use memmap2::Mmap;
+
+use crate::InputError;
+
+/// A memory-mapped, UTF-8 validated input file whose lines borrow from the mapping.
+pub struct MappedInput {
+    map: Mmap,
+}
+
+impl MappedInput {
+    /// Takes over `map` once its contents are known to be UTF-8, the only check `as_str` relies on.
+    fn new(map: Mmap) -> Result<Self, std::str::Utf8Error> {
+        std::str::from_utf8(&map)?;
+        Ok(Self { map })
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `new` is the only way to build a `MappedInput`, and it validated these bytes,
+        // which are mapped read-only and never change under the same assumption as the mapping.
+        #[allow(unsafe_code)]
+        unsafe {
+            std::str::from_utf8_unchecked(&self.map)
+        }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.as_str().lines()
+    }
+}
+
+/// Maps `filename` into memory so its lines can be iterated without per-line allocations.
+#[allow(clippy::missing_errors_doc)]
+pub fn read_lines_mmap<P>(filename: P) -> Result<MappedInput, InputError>
+where P: AsRef<Path>, {
+    let path = filename.as_ref();
+    let file = File::open(path).map_err(|e| InputError::new(path, e))?;
+    // SAFETY: puzzle inputs are not modified while a solution is running;
+    // a concurrent truncation would be a bug in the caller's setup, not here.
+    #[allow(unsafe_code)]
+    let map = unsafe { Mmap::map(&file) }.map_err(|e| InputError::new(path, e))?;
+    MappedInput::new(map).map_err(|e| InputError::new(path, io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::test::write_temp;
+
+    #[test]
+    fn mapped_lines_borrow_from_file() {
+        let path = write_temp("mmap.txt", "L68\r\nL30\nR48\n");
+        let input = crate::read_lines_mmap(&path).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["L68", "L30", "R48"]);
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected() {
+        let path = write_temp("mmap-bad.txt", [0xff, 0xfe, b'\n']);
+        let error = crate::read_lines_mmap(&path).err().unwrap();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+}
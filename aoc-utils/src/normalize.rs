@@ -0,0 +1,115 @@
+use std::io;
+
+/// Strips the artifacts that Windows editors and browser copy-paste leave in inputs:
+/// a leading BOM, `\r` line endings, trailing whitespace, and trailing blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    trim_trailing_whitespace: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self { trim_trailing_whitespace: true }
+    }
+}
+
+impl Normalize {
+    /// Opt-out for puzzles where trailing spaces on a line are significant.
+    #[must_use]
+    pub const fn keep_trailing_whitespace(mut self) -> Self {
+        self.trim_trailing_whitespace = false;
+        self
+    }
+
+    fn line(self, line: &str) -> String {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if self.trim_trailing_whitespace { line.trim_end() } else { line }.to_owned()
+    }
+
+    /// Normalizes a whole input, always ending it with a single newline unless it is empty.
+    #[must_use]
+    pub fn text(self, text: &str) -> String {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let mut lines: Vec<String> = text.split('\n').map(|line| self.line(line)).collect();
+        while lines.last().is_some_and(String::is_empty) {
+            lines.pop();
+        }
+        let mut normalized = lines.join("\n");
+        if !normalized.is_empty() {
+            normalized.push('\n');
+        }
+        normalized
+    }
+
+    /// Normalizes a stream of lines, holding back blank lines until something follows them.
+    pub fn lines<I>(self, lines: I) -> impl Iterator<Item = io::Result<String>>
+    where I: IntoIterator<Item = io::Result<String>>, {
+        let mut lines = lines.into_iter();
+        let mut first = true;
+        let mut pending_blanks = 0usize;
+        let mut next = None;
+        std::iter::from_fn(move || {
+            if pending_blanks > 0 && next.is_some() {
+                pending_blanks -= 1;
+                return Some(Ok(String::new()));
+            }
+            if let Some(line) = next.take() {
+                return Some(Ok(line));
+            }
+            for line in lines.by_ref() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(e) => return Some(Err(e)),
+                };
+                let line = if first { line.strip_prefix('\u{feff}').map(str::to_owned).unwrap_or(line) } else { line };
+                first = false;
+                let line = self.line(&line);
+                if line.is_empty() {
+                    pending_blanks += 1;
+                } else if pending_blanks > 0 {
+                    pending_blanks -= 1;
+                    next = Some(line);
+                    return Some(Ok(String::new()));
+                } else {
+                    return Some(Ok(line));
+                }
+            }
+            None
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::normalize::Normalize;
+
+    fn lines(options: Normalize, text: &str) -> Vec<String> {
+        options.lines(text.split('\n').map(|l| Ok(l.to_owned()))).map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn text_strips_bom_crlf_and_trailing_blanks() {
+        assert_eq!(Normalize::default().text("\u{feff}L68\r\nR48  \r\n\r\n\r\n"), "L68\nR48\n");
+        assert_eq!(Normalize::default().text("\n\n"), "");
+    }
+
+    #[test]
+    fn text_keeps_inner_blank_lines() {
+        assert_eq!(Normalize::default().text("a\r\n\r\nb"), "a\n\nb\n");
+    }
+
+    #[test]
+    fn trailing_whitespace_can_be_kept() {
+        let options = Normalize::default().keep_trailing_whitespace();
+        assert_eq!(options.text("    [D]    \r\n[N] [C]    \r\n"), "    [D]    \n[N] [C]    \n");
+    }
+
+    #[test]
+    fn lines_match_text() {
+        let text = "\u{feff}a\r\n\r\n\r\nb \r\nc\r\n\r\n\r\n";
+        assert_eq!(lines(Normalize::default(), text), vec!["a", "", "", "b", "c"]);
+        assert_eq!(Normalize::default().text(text), "a\n\n\nb\nc\n");
+    }
+
+}
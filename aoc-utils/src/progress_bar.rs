@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns bars off for the rest of the run, e.g. for `--json`. They are also hidden whenever
+/// stderr isn't a terminal.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// A progress bar on stderr for a solution working through a known total, such as lines parsed
+/// or iterations simulated. Cleared when dropped, and a no-op without the `progress` feature.
+#[derive(Debug)]
+pub struct Bar {
+    #[cfg(feature = "progress")]
+    bar: indicatif::ProgressBar,
+}
+
+/// Starts a bar counting up to `total` `unit`s, e.g. `new(1_000_000_000, "steps")`.
+#[cfg(feature = "progress")]
+#[must_use]
+pub fn new(total: u64, unit: &'static str) -> Bar {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Bar { bar: indicatif::ProgressBar::hidden() };
+    }
+    let style = indicatif::ProgressStyle::with_template("{bar:40} {human_pos}/{human_len} {msg} ({eta})")
+        .unwrap_or_else(|_| indicatif::ProgressStyle::default_bar());
+    Bar { bar: indicatif::ProgressBar::new(total).with_style(style).with_message(unit) }
+}
+
+#[cfg(not(feature = "progress"))]
+#[must_use]
+pub const fn new(_total: u64, _unit: &'static str) -> Bar {
+    Bar {}
+}
+
+#[cfg(feature = "progress")]
+impl Bar {
+    pub fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+    }
+
+    pub fn set_position(&self, position: u64) {
+        self.bar.set_position(position);
+    }
+
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.bar.position()
+    }
+
+    /// Advances the bar by one for each item taken from `iter`.
+    pub fn wrap<I: Iterator>(&self, iter: I) -> impl Iterator<Item = I::Item> {
+        self.bar.wrap_iter(iter)
+    }
+}
+
+#[cfg(not(feature = "progress"))]
+impl Bar {
+    pub const fn inc(&self, _delta: u64) {}
+
+    pub const fn set_position(&self, _position: u64) {}
+
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        0
+    }
+
+    pub fn wrap<I: Iterator>(&self, iter: I) -> impl Iterator<Item = I::Item> {
+        iter
+    }
+}
+
+#[cfg(feature = "progress")]
+impl Drop for Bar {
+    fn drop(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+#[cfg(all(test, feature = "progress"))]
+mod test {
+
+    use crate::progress_bar;
+
+    #[test]
+    fn bars_count_what_they_wrap() {
+        let bar = progress_bar::new(5, "lines");
+        assert_eq!(bar.wrap(1..=5).sum::<u32>(), 15);
+        assert_eq!(bar.position(), 5);
+        bar.inc(2);
+        bar.set_position(1);
+        assert_eq!(bar.position(), 1);
+    }
+
+}
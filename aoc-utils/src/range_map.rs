@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use crate::range_set::RangeSet;
+
+/// A map on `i64` that shifts each rule's source range by its offset and leaves every other value
+/// where it is, like a stage of an almanac taking seeds to soil.
+///
+/// Maps work on whole ranges at once, splitting them wherever rules start and end, so billions
+/// of seeds cost no more than a handful.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Non-overlapping `(source, offset)` rules, sorted by source.
+    rules: Vec<(Range<i64>, i64)>,
+}
+
+impl RangeMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `source` to `source` shifted by `offset`. An almanac line `dest src len` is
+    /// `add_rule(src..src + len, dest - src)`.
+    ///
+    /// # Panics
+    /// If `source` overlaps an earlier rule.
+    pub fn add_rule(&mut self, source: Range<i64>, offset: i64) {
+        if source.is_empty() {
+            return;
+        }
+        let i = self.rules.partition_point(|(r, _)| r.end <= source.start);
+        assert!(self.rules.get(i).is_none_or(|(r, _)| r.start >= source.end), "Rules must not overlap.");
+        self.rules.insert(i, (source, offset));
+    }
+
+    /// Where `value` ends up.
+    #[must_use]
+    pub fn map(&self, value: i64) -> i64 {
+        let i = self.rules.partition_point(|(r, _)| r.end <= value);
+        match self.rules.get(i) {
+            Some((r, offset)) if r.start <= value => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where the values in `range` end up, as one range per piece the rules cut it into.
+    #[must_use]
+    pub fn map_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        self.pieces(range).into_iter().map(|(r, offset)| r.start + offset..r.end + offset).collect()
+    }
+
+    /// Where the values in `set` end up.
+    #[must_use]
+    pub fn map_set(&self, set: &RangeSet) -> RangeSet {
+        set.ranges().iter().flat_map(|r| self.map_range(r.clone())).collect()
+    }
+
+    /// The map that applies `self` and then `next`, so a whole chain of stages can be folded into
+    /// one.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let mut composed = Self::new();
+        for (range, offset) in self.pieces(i64::MIN..i64::MAX) {
+            for (image, next_offset) in next.pieces(range.start + offset..range.end + offset) {
+                if offset + next_offset != 0 {
+                    composed.add_rule(image.start - offset..image.end - offset, offset + next_offset);
+                }
+            }
+        }
+        composed
+    }
+
+    /// `range` cut at the rules' edges, each piece with the offset it moves by.
+    fn pieces(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        let mut pieces = Vec::new();
+        let mut start = range.start;
+        let first = self.rules.partition_point(|(r, _)| r.end <= range.start);
+        for (rule, offset) in &self.rules[first..] {
+            if rule.start >= range.end {
+                break;
+            }
+            if start < rule.start {
+                pieces.push((start..rule.start, 0));
+                start = rule.start;
+            }
+            let end = rule.end.min(range.end);
+            pieces.push((start..end, *offset));
+            start = end;
+        }
+        if start < range.end {
+            pieces.push((start..range.end, 0));
+        }
+        pieces
+    }
+}
+
+impl FromIterator<(Range<i64>, i64)> for RangeMap {
+    fn from_iter<I: IntoIterator<Item = (Range<i64>, i64)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, offset) in iter {
+            map.add_rule(source, offset);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{range_map::RangeMap, range_set::RangeSet};
+
+    /// The almanac's seed-to-soil and soil-to-fertilizer maps.
+    fn stages() -> (RangeMap, RangeMap) {
+        let soil = [(98..100, -48), (50..98, 2)].into_iter().collect();
+        let fertilizer = [(15..52, -15), (52..54, -15), (0..15, 39)].into_iter().collect();
+        (soil, fertilizer)
+    }
+
+    #[test]
+    fn values_outside_rules_stay_put() {
+        let (soil, fertilizer) = stages();
+        assert_eq!([79, 14, 55, 13].map(|seed| soil.map(seed)), [81, 14, 57, 13]);
+        assert_eq!([81, 14, 57, 13].map(|seed| fertilizer.map(seed)), [81, 53, 57, 52]);
+        assert_eq!(soil.map(100), 100);
+    }
+
+    #[test]
+    fn ranges_split_at_rule_edges() {
+        let (soil, _) = stages();
+        assert_eq!(soil.map_range(40..60), vec![40..50, 52..62]);
+        assert_eq!(soil.map_range(95..105), vec![97..100, 50..52, 100..105]);
+        let seeds: RangeSet = [79..93, 55..68].into_iter().collect();
+        assert_eq!(soil.map_set(&seeds).ranges(), [57..70, 81..95]);
+    }
+
+    #[test]
+    fn composed_maps_agree_with_chaining() {
+        let (soil, fertilizer) = stages();
+        let both = soil.then(&fertilizer);
+        for seed in -5..120 {
+            assert_eq!(both.map(seed), fertilizer.map(soil.map(seed)), "seed {seed}");
+        }
+        let seeds: RangeSet = [0..60, 90..110].into_iter().collect();
+        assert_eq!(both.map_set(&seeds), fertilizer.map_set(&soil.map_set(&seeds)));
+    }
+
+    #[test]
+    #[should_panic = "Rules must not overlap."]
+    fn overlapping_rules_are_rejected() {
+        let _: RangeMap = [(0..10, 1), (5..15, 2)].into_iter().collect();
+    }
+
+}
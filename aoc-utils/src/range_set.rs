@@ -0,0 +1,184 @@
+use std::ops::Range;
+
+/// A set of `i64`s stored as sorted, disjoint half-open ranges, so it stays small however many
+/// values it covers. Touching ranges are merged, so `1..3` and `3..5` are kept as `1..5`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Takes every value in `range` out, splitting the range it falls inside if need be.
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first == last {
+            return;
+        }
+        let left = self.ranges[first].start..range.start;
+        let right = range.end..self.ranges[last - 1].end;
+        self.ranges.splice(first..last, [left, right].into_iter().filter(|r| !r.is_empty()));
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The disjoint ranges, in increasing order.
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// How many values are in the set.
+    #[must_use]
+    pub fn total_len(&self) -> u64 {
+        self.ranges.iter().map(|r| r.end.abs_diff(r.start)).sum()
+    }
+
+    /// The values in both sets.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = Vec::new();
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in either set.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().cloned());
+        union
+    }
+
+    /// The values in `self` but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The values in `within` that the set doesn't cover, such as the one spot no sensor sees.
+    #[must_use]
+    pub fn gaps(&self, within: Range<i64>) -> Self {
+        Self::from(within).difference(self)
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Range<i64>> for RangeSet {
+    fn extend<I: IntoIterator<Item = Range<i64>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::range_set::RangeSet;
+
+    #[test]
+    fn inserts_coalesce() {
+        let mut set: RangeSet = [5..8, 1..3, 3..4, 10..12].into_iter().collect();
+        assert_eq!(set.ranges(), [1..4, 5..8, 10..12]);
+        set.insert(2..11);
+        assert_eq!(set, RangeSet::from(1..12));
+        set.insert(20..20);
+        assert_eq!((set.total_len(), set.contains(11), set.contains(12), set.contains(0)), (11, true, false, false));
+        assert!(RangeSet::new().is_empty());
+    }
+
+    #[test]
+    fn removal_splits_ranges() {
+        let mut set: RangeSet = [0..10, 20..30].into_iter().collect();
+        set.remove(3..5);
+        assert_eq!(set.ranges(), [0..3, 5..10, 20..30]);
+        set.remove(8..25);
+        assert_eq!(set.ranges(), [0..3, 5..8, 25..30]);
+        set.remove(-5..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: RangeSet = [0..10, 20..30].into_iter().collect();
+        let b: RangeSet = [5..25, 28..40].into_iter().collect();
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+    }
+
+    #[test]
+    fn gaps_find_the_uncovered_spot() {
+        // Sensors cover everything in 0..=20 except x = 14.
+        let covered: RangeSet = [-2..3, 2..14, 15..25].into_iter().collect();
+        assert_eq!(covered.gaps(0..21), RangeSet::from(14..15));
+        assert_eq!(covered.gaps(30..40), RangeSet::from(30..40));
+        assert_eq!(RangeSet::from(i64::MIN..i64::MAX).total_len(), u64::MAX);
+    }
+
+}
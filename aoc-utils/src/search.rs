@@ -0,0 +1,375 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash, ops::{Add, Sub}};
+
+/// A goal state reached by a search, with its cost from the start and the way back to it.
+#[derive(Debug, Clone)]
+pub struct Found<S, C = usize> {
+    pub state: S,
+    /// Steps taken for breadth-first search, or the summed edge costs.
+    pub cost: C,
+    parents: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash, C> Found<S, C> {
+    /// The states from the start to the goal, both included.
+    #[must_use]
+    pub fn path(&self) -> Vec<S> {
+        let mut path: Vec<S> = std::iter::successors(Some(&self.state), |s| self.parents.get(s)).cloned().collect();
+        path.reverse();
+        path
+    }
+}
+
+/// Every cheapest way from the start to the goal states reached at the lowest cost, for puzzles
+/// that ask about all the best paths rather than one.
+#[derive(Debug, Clone)]
+pub struct AllFound<S, C = usize> {
+    /// The goal states reached at `cost`, in the order they were found.
+    pub goals: Vec<S>,
+    pub cost: C,
+    /// Every state's parents along the cheapest paths to it.
+    parents: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllFound<S, C> {
+    /// Every state on at least one cheapest path, the start and goals included.
+    #[must_use]
+    pub fn states(&self) -> HashSet<S> {
+        let mut seen: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for parent in self.parents.get(&state).into_iter().flatten() {
+                if seen.insert(parent.clone()) {
+                    stack.push(parent.clone());
+                }
+            }
+        }
+        seen
+    }
+
+    /// Each cheapest path from the start to a goal. There can be exponentially many; prefer
+    /// [`Self::states`] when only the states on them matter.
+    #[must_use]
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        let mut stack: Vec<Vec<S>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+        while let Some(reversed) = stack.pop() {
+            match self.parents.get(&reversed[reversed.len() - 1]) {
+                Some(parents) => stack.extend(parents.iter().map(|parent| {
+                    let mut longer = reversed.clone();
+                    longer.push(parent.clone());
+                    longer
+                })),
+                None => paths.push(reversed.into_iter().rev().collect()),
+            }
+        }
+        paths
+    }
+}
+
+/// Breadth-first search from `start` to the nearest state satisfying `goal`, expanding each state
+/// with `successors`. Every step costs one; `None` if no reachable state is a goal.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut goal: impl FnMut(&S) -> bool) -> Option<Found<S>>
+where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, {
+    let mut seen = HashSet::from([start.clone()]);
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        if goal(&state) {
+            return Some(Found { state, cost: distance, parents });
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Every state reachable from `start`, each listed when depth-first search first reaches it.
+pub fn dfs_preorder<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Vec<S>
+where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, {
+    depth_first(start, successors).0
+}
+
+/// Every state reachable from `start`, each listed once depth-first search has finished with all
+/// of its successors.
+pub fn dfs_postorder<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Vec<S>
+where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, {
+    depth_first(start, successors).1
+}
+
+/// Depth-first search with an explicit stack, so deep graphs can't overflow the call stack.
+/// Returns the states in pre-order and in post-order.
+fn depth_first<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> (Vec<S>, Vec<S>)
+where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, {
+    let mut seen = HashSet::from([start.clone()]);
+    let (mut pre, mut post) = (vec![start.clone()], Vec::new());
+    let mut stack = vec![(successors(&start).into_iter(), start)];
+    while let Some((children, state)) = stack.last_mut() {
+        if let Some(next) = children.find(|next| !seen.contains(next)) {
+            seen.insert(next.clone());
+            pre.push(next.clone());
+            stack.push((successors(&next).into_iter(), next));
+        } else {
+            post.push(state.clone());
+            stack.pop();
+        }
+    }
+    (pre, post)
+}
+
+/// The cheapest cost from `start` to every reachable state, where `successors` yields each
+/// neighbouring state with the cost of moving there. Costs must not be negative.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> HashMap<S, C>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (S, C)>, {
+    cheapest(start, successors, |_| C::default(), |_| false).0
+}
+
+/// The cheapest way from `start` to a state satisfying `goal`, as for [`dijkstra`]; `None` if no
+/// reachable state is a goal.
+pub fn dijkstra_to<S, C, I>(start: S, successors: impl FnMut(&S) -> I, goal: impl FnMut(&S) -> bool) -> Option<Found<S, C>>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (S, C)>, {
+    let (costs, parents, state) = cheapest(start, successors, |_| C::default(), goal);
+    let state = state?;
+    Some(Found { cost: costs[&state], state, parents })
+}
+
+/// Like [`bfs`], but keeps every shortest path to every goal at the nearest distance.
+pub fn bfs_all<S, I>(start: S, mut successors: impl FnMut(&S) -> I, goal: impl FnMut(&S) -> bool) -> Option<AllFound<S>>
+where S: Clone + Eq + Hash, I: IntoIterator<Item = S>, {
+    dijkstra_all(start, |state| successors(state).into_iter().map(|next| (next, 1)), goal)
+}
+
+/// Like [`dijkstra_to`], but keeps every cheapest path to every goal reached at the lowest cost.
+pub fn dijkstra_all<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I, mut goal: impl FnMut(&S) -> bool) -> Option<AllFound<S, C>>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (S, C)>, {
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = BinaryHeap::from([Queued { priority: C::default(), cost: C::default(), state: start }]);
+    let mut found: Option<AllFound<S, C>> = None;
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if found.as_ref().is_some_and(|found| cost > found.cost) {
+            break;
+        }
+        if goal(&state) {
+            found.get_or_insert_with(|| AllFound { goals: Vec::new(), cost, parents: HashMap::new() }).goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(&best) if next_cost > best => {}
+                Some(&best) if next_cost == best => parents.entry(next).or_default().push(state.clone()),
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    parents.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued { priority: next_cost, cost: next_cost, state: next });
+                }
+            }
+        }
+    }
+    found.map(|found| AllFound { parents, ..found })
+}
+
+/// Like [`dijkstra_to`], but states are explored in order of their cost plus `heuristic`'s
+/// estimate of the cost left to a goal, such as the Manhattan distance on a grid.
+///
+/// The heuristic must never overestimate, or the path found may not be the cheapest. Debug
+/// builds check that along the returned path.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C> + Sub<Output = C> + std::fmt::Debug, I: IntoIterator<Item = (S, C)>, {
+    let (costs, parents, state) = cheapest(start, successors, &mut heuristic, goal);
+    let state = state?;
+    let found = Found { cost: costs[&state], state, parents };
+    if cfg!(debug_assertions) {
+        for step in found.path() {
+            let (estimate, left) = (heuristic(&step), found.cost - costs[&step]);
+            debug_assert!(estimate <= left, "Heuristic overestimates: {estimate:?} for a state {left:?} from the goal.");
+        }
+    }
+    Some(found)
+}
+
+/// A state waiting in the priority queue, ordered so the lowest `priority` (cost plus estimate)
+/// is popped first.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+/// Dijkstra's algorithm (A* with a non-zero `heuristic`), stopping early at the first goal
+/// popped. Returns the best known costs, each state's parent and the goal, if one was reached.
+#[allow(clippy::type_complexity)]
+fn cheapest<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> (HashMap<S, C>, HashMap<S, S>, Option<S>)
+where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output = C>, I: IntoIterator<Item = (S, C)>, {
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Queued { priority: heuristic(&start), cost: C::default(), state: start }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if goal(&state) {
+            return (costs, parents, Some(state));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                queue.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+    (costs, parents, None)
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{grid::Grid2D, search::{astar, bfs, bfs_all, dfs_postorder, dfs_preorder, dijkstra, dijkstra_all, dijkstra_to}};
+
+    /// A small weighted graph where the direct edges are dearer than the detours.
+    fn edges(node: char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 2)],
+            'c' => vec![('b', 3), ('d', 8)],
+            'b' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_finds_the_shortest_path() {
+        let maze = Grid2D::<char>::from_lines(["S.#", ".##", "..E"]).unwrap();
+        let found = bfs((0, 0), |&pos| maze.neighbors4(pos).filter(|&p| maze[p] != '#'), |&pos| maze[pos] == 'E').unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.state, (2, 2));
+        assert_eq!(found.path(), vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn bfs_works_on_implicit_states() {
+        let found = bfs(1_u32, |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.path(), vec![1, 2, 4, 5, 10]);
+        assert_eq!(bfs(0_u8, |&n| n.checked_add(1), |_| true).unwrap().path(), vec![0]);
+        assert!(bfs(0_u8, |&n| n.checked_add(1), |_| false).is_none());
+    }
+
+    #[test]
+    fn dfs_lists_states_in_both_orders() {
+        let tree = |&n: &u32| if n < 4 { vec![2 * n, 2 * n + 1] } else { vec![] };
+        assert_eq!(dfs_preorder(1, tree), vec![1, 2, 4, 5, 3, 6, 7]);
+        assert_eq!(dfs_postorder(1, tree), vec![4, 5, 2, 6, 7, 3, 1]);
+        assert_eq!(dfs_preorder('a', |&n| edges(n).into_iter().map(|(m, _)| m)), vec!['a', 'b', 'd', 'c']);
+        assert_eq!(dfs_postorder(0_u32, |&n| [(n + 1) % 100_000]).len(), 100_000);
+    }
+
+    #[test]
+    fn dijkstra_costs_every_reachable_state() {
+        let costs = dijkstra('a', |&n| edges(n));
+        assert_eq!(costs.len(), 4);
+        assert_eq!((costs[&'a'], costs[&'b'], costs[&'c'], costs[&'d']), (0, 5, 2, 6));
+        assert!(!dijkstra('d', |&n| edges(n)).contains_key(&'a'));
+    }
+
+    #[test]
+    fn dijkstra_to_returns_the_cheapest_path() {
+        let found = dijkstra_to('a', |&n| edges(n), |&n| n == 'd').unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path(), vec!['a', 'c', 'b', 'd']);
+        assert!(dijkstra_to('b', |&n| edges(n), |&n| n == 'a').is_none());
+
+        let grid = Grid2D::from_lines_with(["131", "999", "111"], |c| c.to_digit(10).unwrap()).unwrap();
+        let found = dijkstra_to((0, 0), |&pos| grid.neighbor_cells4(pos).map(|(p, &risk)| (p, risk)), |&pos| pos == (2, 2)).unwrap();
+        assert_eq!(found.cost, 12);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra_with_a_manhattan_heuristic() {
+        let maze = Grid2D::<char>::from_lines(["....#...", ".##.#.#.", ".#..#.#.", ".#.##.#.", "......#."]).unwrap();
+        let goal: (usize, usize) = (7, 4);
+        let successors = |&pos: &(usize, usize)| maze.neighbors4(pos).filter(|&p| maze[p] != '#').map(|p| (p, 1_usize));
+        let manhattan = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+        let found = astar((0, 0), successors, manhattan, |&pos| pos == goal).unwrap();
+        assert_eq!(found.cost, dijkstra_to((0, 0), successors, |&pos| pos == goal).unwrap().cost);
+        assert_eq!(found.path().len(), found.cost + 1);
+        assert!(astar((0, 0), successors, manhattan, |&pos| pos == (4, 0)).is_none());
+    }
+
+    #[test]
+    #[should_panic = "Heuristic overestimates"]
+    #[cfg(debug_assertions)]
+    fn astar_rejects_an_overestimating_heuristic() {
+        let _ = astar('a', |&n| edges(n), |&n| if n == 'a' { 100 } else { 0 }, |&n| n == 'd');
+    }
+
+    #[test]
+    fn all_cheapest_paths_are_kept() {
+        // Two equally cheap routes from a to d, and a dearer one.
+        let edges = |&n: &char| match n {
+            'a' => vec![('b', 1), ('c', 2), ('d', 9)],
+            'b' => vec![('d', 3)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        };
+        let found = dijkstra_all('a', edges, |&n| n == 'd').unwrap();
+        assert_eq!((found.goals.clone(), found.cost), (vec!['d'], 4));
+        let mut paths = found.paths();
+        paths.sort();
+        assert_eq!(paths, vec![vec!['a', 'b', 'd'], vec!['a', 'c', 'd']]);
+        assert_eq!(found.states(), "abcd".chars().collect());
+        assert!(dijkstra_all('d', edges, |&n| n == 'a').is_none());
+    }
+
+    #[test]
+    fn bfs_all_covers_every_shortest_route_and_goal() {
+        let grid = Grid2D::<char>::from_lines(["S..", ".#.", "..E"]).unwrap();
+        let found = bfs_all((0, 0), |&pos| grid.neighbors4(pos).filter(|&p| grid[p] != '#'), |&pos| grid[pos] == 'E').unwrap();
+        assert_eq!(found.cost, 4);
+        assert_eq!(found.paths().len(), 2);
+        assert_eq!(found.states().len(), 8);
+
+        let ends = bfs_all(0_i32, |&n| [n - 1, n + 1], |&n| n.abs() == 2).unwrap();
+        assert_eq!(ends.goals.len(), 2);
+        assert_eq!(ends.states().len(), 5);
+    }
+
+}
@@ -0,0 +1,279 @@
+use std::ops::{Add, Range};
+
+/// How a [`SegmentTree`] sums up a range of values, and how adding to every value in a range
+/// changes that summary.
+pub trait Aggregate {
+    /// Both the values and the amounts added to them; `Default` adds nothing.
+    type Value: Copy + PartialEq + Default + Add<Output = Self::Value>;
+
+    /// The summary of no values at all.
+    const IDENTITY: Self::Value;
+
+    fn combine(a: Self::Value, b: Self::Value) -> Self::Value;
+
+    /// The summary of `len` values after `delta` is added to each of them.
+    fn add(summary: Self::Value, delta: Self::Value, len: usize) -> Self::Value;
+}
+
+/// Range sums.
+#[derive(Debug, Clone, Copy)]
+pub struct Sum;
+
+/// Range minimums.
+#[derive(Debug, Clone, Copy)]
+pub struct Min;
+
+/// Range maximums.
+#[derive(Debug, Clone, Copy)]
+pub struct Max;
+
+impl Aggregate for Sum {
+    type Value = i64;
+    const IDENTITY: i64 = 0;
+
+    fn combine(a: i64, b: i64) -> i64 {
+        a + b
+    }
+
+    fn add(summary: i64, delta: i64, len: usize) -> i64 {
+        summary + delta * i64::try_from(len).expect("Segment lengths fit in an i64.")
+    }
+}
+
+impl Aggregate for Min {
+    type Value = i64;
+    const IDENTITY: i64 = i64::MAX;
+
+    fn combine(a: i64, b: i64) -> i64 {
+        a.min(b)
+    }
+
+    fn add(summary: i64, delta: i64, _: usize) -> i64 {
+        summary + delta
+    }
+}
+
+impl Aggregate for Max {
+    type Value = i64;
+    const IDENTITY: i64 = i64::MIN;
+
+    fn combine(a: i64, b: i64) -> i64 {
+        a.max(b)
+    }
+
+    fn add(summary: i64, delta: i64, _: usize) -> i64 {
+        summary + delta
+    }
+}
+
+/// Values at `0..len` that can have an amount added to a whole range, or be summarised over
+/// one, in `O(log len)` each.
+///
+/// Additions to a range are held at the highest nodes that cover it and only pushed further down
+/// when part of that range is changed again.
+#[derive(Debug, Clone)]
+pub struct SegmentTree<A: Aggregate> {
+    len: usize,
+    /// The summary of each node's range, children of node `i` at `2i` and `2i + 1`.
+    summary: Vec<A::Value>,
+    /// What is still to be added to everything under each node.
+    pending: Vec<A::Value>,
+}
+
+impl<A: Aggregate> SegmentTree<A> {
+    /// `len` values, all `Default`.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        std::iter::repeat_n(A::Value::default(), len).collect()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The value at `i`.
+    ///
+    /// # Panics
+    /// If `i` is out of range.
+    #[must_use]
+    pub fn get(&self, i: usize) -> A::Value {
+        self.query(i..i + 1)
+    }
+
+    /// The summary of the values in `range`, [`Aggregate::IDENTITY`] if it's empty.
+    ///
+    /// # Panics
+    /// If `range` reaches past the end.
+    #[must_use]
+    pub fn query(&self, range: Range<usize>) -> A::Value {
+        assert!(range.end <= self.len, "Range {range:?} is out of bounds for length {}.", self.len);
+        if range.is_empty() {
+            return A::IDENTITY;
+        }
+        self.query_node(1, 0..self.len, &range, A::Value::default())
+    }
+
+    /// Adds `delta` to every value in `range`.
+    ///
+    /// # Panics
+    /// If `range` reaches past the end.
+    pub fn add(&mut self, range: Range<usize>, delta: A::Value) {
+        assert!(range.end <= self.len, "Range {range:?} is out of bounds for length {}.", self.len);
+        if !range.is_empty() {
+            self.add_node(1, 0..self.len, &range, delta);
+        }
+    }
+
+    /// Replaces the value at `i`.
+    ///
+    /// # Panics
+    /// If `i` is out of range.
+    pub fn set(&mut self, i: usize, value: A::Value) {
+        assert!(i < self.len, "Index {i} is out of bounds for length {}.", self.len);
+        let (mut node, mut span) = (1, 0..self.len);
+        let mut path = Vec::new();
+        while span.len() > 1 {
+            self.push(node, &span);
+            path.push(node);
+            let mid = span.start + span.len() / 2;
+            (node, span) = if i < mid { (2 * node, span.start..mid) } else { (2 * node + 1, mid..span.end) };
+        }
+        self.summary[node] = value;
+        for &node in path.iter().rev() {
+            self.summary[node] = A::combine(self.summary[2 * node], self.summary[2 * node + 1]);
+        }
+    }
+
+    fn query_node(&self, node: usize, span: Range<usize>, range: &Range<usize>, above: A::Value) -> A::Value {
+        if range.end <= span.start || span.end <= range.start {
+            return A::IDENTITY;
+        }
+        if range.start <= span.start && span.end <= range.end {
+            return A::add(self.summary[node], above, span.len());
+        }
+        let above = above + self.pending[node];
+        let mid = span.start + span.len() / 2;
+        A::combine(
+            self.query_node(2 * node, span.start..mid, range, above),
+            self.query_node(2 * node + 1, mid..span.end, range, above),
+        )
+    }
+
+    fn add_node(&mut self, node: usize, span: Range<usize>, range: &Range<usize>, delta: A::Value) {
+        if range.end <= span.start || span.end <= range.start {
+            return;
+        }
+        if range.start <= span.start && span.end <= range.end {
+            self.apply(node, delta, span.len());
+            return;
+        }
+        self.push(node, &span);
+        let mid = span.start + span.len() / 2;
+        self.add_node(2 * node, span.start..mid, range, delta);
+        self.add_node(2 * node + 1, mid..span.end, range, delta);
+        self.summary[node] = A::combine(self.summary[2 * node], self.summary[2 * node + 1]);
+    }
+
+    fn apply(&mut self, node: usize, delta: A::Value, len: usize) {
+        self.summary[node] = A::add(self.summary[node], delta, len);
+        self.pending[node] = self.pending[node] + delta;
+    }
+
+    /// Hands `node`'s pending addition down to its children.
+    fn push(&mut self, node: usize, span: &Range<usize>) {
+        let delta = std::mem::take(&mut self.pending[node]);
+        if delta != A::Value::default() {
+            let mid = span.start + span.len() / 2;
+            self.apply(2 * node, delta, mid - span.start);
+            self.apply(2 * node + 1, delta, span.end - mid);
+        }
+    }
+
+    fn build(&mut self, node: usize, span: Range<usize>, values: &[A::Value]) {
+        if span.len() == 1 {
+            self.summary[node] = values[span.start];
+            return;
+        }
+        let mid = span.start + span.len() / 2;
+        self.build(2 * node, span.start..mid, values);
+        self.build(2 * node + 1, mid..span.end, values);
+        self.summary[node] = A::combine(self.summary[2 * node], self.summary[2 * node + 1]);
+    }
+}
+
+impl<A: Aggregate> FromIterator<A::Value> for SegmentTree<A> {
+    fn from_iter<I: IntoIterator<Item = A::Value>>(iter: I) -> Self {
+        let values: Vec<A::Value> = iter.into_iter().collect();
+        let nodes = 4 * values.len().max(1);
+        let mut tree = Self { len: values.len(), summary: vec![A::IDENTITY; nodes], pending: vec![A::Value::default(); nodes] };
+        if !values.is_empty() {
+            tree.build(1, 0..values.len(), &values);
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::segment_tree::{Max, Min, SegmentTree, Sum};
+
+    #[test]
+    fn sums_follow_range_additions() {
+        let mut tree: SegmentTree<Sum> = [1, 2, 3, 4, 5].into_iter().collect();
+        assert_eq!(tree.query(0..5), 15);
+        tree.add(1..4, 10);
+        assert_eq!((tree.query(0..5), tree.query(2..3), tree.get(4)), (45, 13, 5));
+        tree.set(2, 0);
+        assert_eq!((tree.query(1..4), tree.query(3..3)), (26, 0));
+    }
+
+    #[test]
+    fn min_and_max_track_extremes() {
+        let mut low: SegmentTree<Min> = SegmentTree::new(8);
+        let mut high: SegmentTree<Max> = SegmentTree::new(8);
+        for (range, delta) in [(0..8, 3), (2..5, -4), (4..8, 6)] {
+            low.add(range.clone(), delta);
+            high.add(range, delta);
+        }
+        // Values are now 3, 3, -1, -1, 5, 9, 9, 9.
+        assert_eq!((low.query(0..8), low.query(4..8), low.query(0..2)), (-1, 5, 3));
+        assert_eq!((high.query(0..8), high.query(2..4), high.query(0..5)), (9, -1, 5));
+        assert_eq!(low.query(1..1), i64::MAX);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 7_u64;
+        let mut next = |bound: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            usize::try_from((seed >> 33) % bound).unwrap()
+        };
+        let mut values = vec![0_i64; 37];
+        let mut tree: SegmentTree<Sum> = SegmentTree::new(values.len());
+        for step in 0..500 {
+            let (a, b) = (next(38), next(38));
+            let range = a.min(b)..a.max(b);
+            match step % 3 {
+                0 => {
+                    let delta = i64::try_from(next(21)).unwrap() - 10;
+                    tree.add(range.clone(), delta);
+                    values[range].iter_mut().for_each(|v| *v += delta);
+                }
+                1 if a < values.len() => {
+                    values[a] = i64::try_from(b).unwrap();
+                    tree.set(a, values[a]);
+                }
+                _ => assert_eq!(tree.query(range.clone()), values[range].iter().sum::<i64>()),
+            }
+        }
+        assert!(SegmentTree::<Sum>::new(0).is_empty());
+    }
+
+}
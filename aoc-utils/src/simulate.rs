@@ -0,0 +1,108 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::math::congruence;
+
+/// A simulation that settled into a loop: after `start` steps it repeats every `length` steps.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub length: usize,
+    /// Every state up to the one where the loop first closes, the initial state first.
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The state after `n` steps, without running them, for puzzles asking about step
+    /// 1,000,000,000.
+    #[must_use]
+    pub fn state_after(&self, n: usize) -> &S {
+        if n < self.start {
+            return &self.states[n];
+        }
+        &self.states[self.start + (n - self.start) % self.length]
+    }
+}
+
+/// Runs `step` from `initial` until a state repeats, telling states apart by `key` (which can
+/// leave out details that don't affect the future, or return the state itself).
+///
+/// Never returns if the states never repeat.
+pub fn find_cycle<S, K>(initial: S, mut step: impl FnMut(&S) -> S, mut key: impl FnMut(&S) -> K) -> Cycle<S>
+where K: Eq + Hash, {
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(&states[states.len() - 1]);
+        let k = key(&next);
+        if let Some(&start) = seen.get(&k) {
+            return Cycle { start, length: states.len() - start, states };
+        }
+        seen.insert(k, states.len());
+        states.push(next);
+    }
+}
+
+/// The first time every signal fires together, or `None` if they never line up before `u64::MAX`.
+///
+/// A signal `(offset, period)` fires at `offset`, `offset + period`, `offset + 2 * period` and so
+/// on. With every offset equal to its period this is their least common multiple; otherwise the
+/// offsets are reconciled by the Chinese remainder theorem, and periods need not be coprime.
+///
+/// # Panics
+/// If a period is zero.
+#[must_use]
+pub fn first_coincidence(signals: &[(u64, u64)]) -> Option<u64> {
+    assert!(signals.iter().all(|&(_, period)| period > 0), "Periods must be positive.");
+    let (residue, modulus) = signals
+        .iter()
+        .try_fold((0, 1), |combined, &(offset, period)| congruence(combined, (i128::from(offset), i128::from(period))))?;
+    let latest = signals.iter().map(|&(offset, _)| i128::from(offset)).max().unwrap_or(0);
+    let first = if residue < latest { residue + (latest - residue + modulus - 1) / modulus * modulus } else { residue };
+    u64::try_from(first).ok()
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::simulate::{find_cycle, first_coincidence};
+
+    #[test]
+    fn cycle_start_and_length() {
+        // 1, 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4...
+        let cycle = find_cycle(1_u32, |&n| n * 2 % 100, |&n| n);
+        assert_eq!((cycle.start, cycle.length), (2, 20));
+        assert_eq!(*cycle.state_after(0), 1);
+        assert_eq!(*cycle.state_after(7), 28);
+        assert_eq!(*cycle.state_after(22), 4);
+        assert_eq!(*cycle.state_after(1_000_000_000), 76);
+    }
+
+    #[test]
+    fn key_can_ignore_part_of_the_state() {
+        // The step count rides along but doesn't decide where the loop closes.
+        let cycle = find_cycle((0_usize, 'a'), |&(steps, c)| (steps + 1, if c == 'c' { 'a' } else { char::from(c as u8 + 1) }), |&(_, c)| c);
+        assert_eq!((cycle.start, cycle.length), (0, 3));
+        assert_eq!(cycle.state_after(10).1, 'b');
+    }
+
+    #[test]
+    fn signals_coincide_at_the_lcm() {
+        // Ghosts reaching their end every 2, 3 and 4 steps, first at step 2, 3 and 4.
+        assert_eq!(first_coincidence(&[(2, 2), (3, 3), (4, 4)]), Some(12));
+        assert_eq!(first_coincidence(&[(5, 5)]), Some(5));
+        assert_eq!(first_coincidence(&[]), Some(0));
+    }
+
+    #[test]
+    fn offsets_are_reconciled() {
+        // Buses 17, 13 and 19 departing 0, 2 and 3 minutes apart: t ≡ 0 (17), t ≡ -2 (13), t ≡ -3 (19).
+        assert_eq!(first_coincidence(&[(0, 17), (11, 13), (16, 19)]), Some(3417));
+        // Non-coprime periods that agree, and ones that never can.
+        assert_eq!(first_coincidence(&[(1, 4), (3, 6)]), Some(9));
+        assert_eq!(first_coincidence(&[(1, 4), (2, 6)]), None);
+        // A late first firing pushes the answer a whole period on.
+        assert_eq!(first_coincidence(&[(1, 3), (7, 2)]), Some(7));
+        assert_eq!(first_coincidence(&[(0, u64::MAX), (1, u64::MAX - 1)]), None);
+    }
+
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use crate::geometry::Point2;
+
+/// An unbounded grid holding only its occupied cells, for puzzles whose coordinates go negative
+/// or grow without limit. Keeps track of the box around every occupied cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+    bounds: Option<(Point2<i64>, Point2<i64>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self { cells: HashMap::new(), bounds: None }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the cell at `pos`, returning what was there before.
+    pub fn insert(&mut self, pos: Point2<i64>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (pos, pos),
+            Some((min, max)) => {
+                (Point2::new(min.x.min(pos.x), min.y.min(pos.y)), Point2::new(max.x.max(pos.x), max.y.max(pos.y)))
+            }
+        });
+        self.cells.insert(pos, value)
+    }
+
+    /// Empties the cell at `pos`, shrinking the bounds if it was on their edge.
+    pub fn remove(&mut self, pos: Point2<i64>) -> Option<T> {
+        let value = self.cells.remove(&pos)?;
+        if self.bounds.is_some_and(|(min, max)| pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y) {
+            self.bounds = self.cells.keys().fold(None, |bounds, &p| {
+                Some(bounds.map_or((p, p), |(min, max): (Point2<i64>, Point2<i64>)| {
+                    (Point2::new(min.x.min(p.x), min.y.min(p.y)), Point2::new(max.x.max(p.x), max.y.max(p.y)))
+                }))
+            });
+        }
+        Some(value)
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: Point2<i64>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point2<i64>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    #[must_use]
+    pub fn contains(&self, pos: Point2<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest and largest corner of the box around every occupied cell, inclusive.
+    #[must_use]
+    pub const fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        self.bounds
+    }
+
+    /// The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Draws the bounding box row by row, `cell` choosing each character (`None` for empty cells).
+    pub fn render(&self, cell: impl Fn(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else { return String::new() };
+        (min.y..=max.y)
+            .map(|y| (min.x..=max.x).map(|x| cell(self.get(Point2::new(x, y)))).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point2<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2<i64>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{geometry::Point2, sparse_grid::SparseGrid};
+
+    #[test]
+    fn cells_can_be_anywhere() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.insert(Point2::new(-5, 3), 'a'), None);
+        assert_eq!(grid.insert(Point2::new(1_000_000, -2), 'b'), None);
+        assert_eq!(grid.insert(Point2::new(-5, 3), 'c'), Some('a'));
+        assert_eq!(grid.get(Point2::new(-5, 3)), Some(&'c'));
+        assert_eq!(grid.get(Point2::new(0, 0)), None);
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.bounds(), Some((Point2::new(-5, -2), Point2::new(1_000_000, 3))));
+    }
+
+    #[test]
+    fn removing_an_edge_cell_shrinks_the_bounds() {
+        let mut grid: SparseGrid<()> = [(0, 0), (2, 1), (5, 5)].into_iter().map(|(x, y)| (Point2::new(x, y), ())).collect();
+        assert_eq!(grid.remove(Point2::new(5, 5)), Some(()));
+        assert_eq!(grid.bounds(), Some((Point2::new(0, 0), Point2::new(2, 1))));
+        assert_eq!(grid.remove(Point2::new(5, 5)), None);
+        grid.remove(Point2::new(0, 0));
+        grid.remove(Point2::new(2, 1));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn render_draws_the_bounding_box() {
+        let grid: SparseGrid<char> = [((-1, -1), '#'), ((1, 0), 'o')].into_iter().map(|((x, y), c)| (Point2::new(x, y), c)).collect();
+        assert_eq!(grid.render(|cell| cell.copied().unwrap_or('.')), "#..\n..o");
+        assert_eq!(SparseGrid::<char>::new().render(|_| '#'), "");
+    }
+
+}
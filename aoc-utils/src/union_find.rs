@@ -0,0 +1,229 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Disjoint sets over the indices `0..len`, merged with [`Self::union`].
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// `len` indices, each in a set of its own.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self { parent: (0..len).collect(), rank: vec![0; len], size: vec![1; len], sets: len }
+    }
+
+    /// Adds an index in a set of its own, returning it.
+    pub fn push(&mut self) -> usize {
+        self.parent.push(self.parent.len());
+        self.rank.push(0);
+        self.size.push(1);
+        self.sets += 1;
+        self.parent.len() - 1
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// How many disjoint sets there are.
+    #[must_use]
+    pub const fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// The representative of `i`'s set, pointing everything on the way straight at it.
+    ///
+    /// # Panics
+    /// If `i` is out of range.
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut i = i;
+        while self.parent[i] != root {
+            i = std::mem::replace(&mut self.parent[i], root);
+        }
+        root
+    }
+
+    /// Merges the sets holding `a` and `b`, returning `false` if they were already one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (root, child) = if self.rank[a] < self.rank[b] { (b, a) } else { (a, b) };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of indices in `i`'s set.
+    pub fn size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.size[root]
+    }
+
+    /// Every set, each in ascending order, ordered by their smallest index.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut by_root: HashMap<usize, usize> = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.sets);
+        for i in 0..self.len() {
+            let root = self.find(i);
+            let n = *by_root.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[n].push(i);
+        }
+        components
+    }
+}
+
+/// A [`UnionFind`] over arbitrary keys, added the first time they're mentioned.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    sets: UnionFind,
+    index: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self { sets: UnionFind::new(0), index: HashMap::new(), keys: Vec::new() }
+    }
+}
+
+impl<K: Clone + Eq + Hash> KeyedUnionFind<K> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `key`'s index, adding it in a set of its own if it's new.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+        let i = self.sets.push();
+        self.index.insert(key.clone(), i);
+        self.keys.push(key);
+        i
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    #[must_use]
+    pub const fn sets(&self) -> usize {
+        self.sets.sets()
+    }
+
+    /// The representative key of `key`'s set, or `None` if `key` was never added.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let root = self.sets.find(*self.index.get(key)?);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets holding `a` and `b`, adding either if new. `false` if they were already
+    /// one set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Whether both keys were added and are in the same set.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.index.get(a), self.index.get(b)) {
+            (Some(&a), Some(&b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// The number of keys in `key`'s set, 0 if it was never added.
+    pub fn size(&mut self, key: &K) -> usize {
+        self.index.get(key).copied().map_or(0, |i| self.sets.size(i))
+    }
+
+    /// Every set, ordered by when their first key was added.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.sets.components().into_iter().map(|set| set.into_iter().map(|i| self.keys[i].clone()).collect()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::union_find::{KeyedUnionFind, UnionFind};
+
+    #[test]
+    fn unions_merge_sets() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 1));
+        assert!(sets.union(2, 3));
+        assert!(!sets.union(0, 4));
+        assert_eq!(sets.sets(), 3);
+        assert!(sets.connected(0, 4));
+        assert!(!sets.connected(0, 2));
+        assert_eq!((sets.size(4), sets.size(3), sets.size(5)), (3, 2, 1));
+        assert_eq!(sets.components(), vec![vec![0, 1, 4], vec![2, 3], vec![5]]);
+        assert_eq!(sets.push(), 6);
+        assert_eq!(sets.sets(), 4);
+    }
+
+    #[test]
+    fn long_chains_are_compressed() {
+        let mut sets = UnionFind::new(100_000);
+        for i in 1..sets.len() {
+            sets.union(i - 1, i);
+        }
+        assert_eq!(sets.sets(), 1);
+        assert_eq!(sets.size(12_345), 100_000);
+    }
+
+    #[test]
+    fn keys_are_added_on_first_mention() {
+        let mut sets = KeyedUnionFind::new();
+        sets.union("kh", "tc");
+        sets.union("qp", "kh");
+        sets.union("de", "cg");
+        sets.insert("yn");
+        assert_eq!(sets.len(), 6);
+        assert_eq!(sets.sets(), 3);
+        assert!(sets.connected(&"tc", &"qp"));
+        assert!(!sets.connected(&"tc", &"zz"));
+        let root = *sets.find(&"qp").unwrap();
+        assert_eq!(sets.find(&"kh"), Some(&root));
+        assert_eq!(sets.find(&"zz"), None);
+        assert_eq!((sets.size(&"kh"), sets.size(&"zz")), (3, 0));
+        assert_eq!(sets.components(), vec![vec!["kh", "tc", "qp"], vec!["de", "cg"], vec!["yn"]]);
+    }
+
+}
@@ -0,0 +1,49 @@
+//! Criterion benchmarks for every day, driven from the real inputs in `inputs/`.
+//!
+//! Run with `cargo bench`, or `cargo bench -- day1/` for a single day.
+
+use std::hint::black_box;
+
+use aoc2025::{default_input, solution::Solution};
+use aoc_utils::read_to_string;
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Adds `dayN/parse`, `dayN/part1` and `dayN/part2` benchmarks, skipping days without an input.
+// Unused when every day's feature is off.
+#[allow(dead_code)]
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let Ok(input) = read_to_string(default_input(day)) else {
+        eprintln!("Skipping day {day}: no input at {}", default_input(day).display());
+        return;
+    };
+    let parsed = S::parse(&input);
+
+    let mut group = c.benchmark_group(format!("day{day}"));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+/// Generates the `days` benchmark function from `day, feature => Solution` entries, skipping
+/// days whose feature is off.
+macro_rules! bench_days {
+    ($($day:literal, $feature:literal => $solution:ty),* $(,)?) => {
+        fn days(c: &mut Criterion) {
+            let benches: &[(u8, fn(&mut Criterion, u8))] = &[$(
+                #[cfg(feature = $feature)]
+                ($day, bench_day::<$solution>),
+            )*];
+            for &(day, bench) in benches {
+                bench(c, day);
+            }
+        }
+    };
+}
+
+bench_days! {
+    1, "day1" => aoc2025::days::day1::Day1,
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
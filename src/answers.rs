@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::{Path, PathBuf}};
+
+use serde::Deserialize;
+
+use crate::solution::Answer;
+
+/// Known-correct answers, read from `answers.toml`, optionally with those of the examples
+/// `inputs/dayN.example.txt`, `inputs/dayN.example2.txt`, ...:
+///
+/// ```toml
+/// [1]
+/// part1 = 1023
+/// part2 = 5923
+/// example = { part1 = 3, part2 = 6 }
+/// example2 = { part2 = 11 }
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Input), [Option<String>; 2]>);
+
+/// Which input of a day an answer belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Input {
+    Real,
+    Example(u8),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Part(Expected),
+    Example(ExampleAnswers),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExampleAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+/// How one part's answer compared against `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    /// No expected answer is recorded for this part.
+    Unknown,
+}
+
+impl Answers {
+    /// `answers.toml` in the crate root, or `answers-<year>.toml` for other events.
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        let year = crate::calendar::year();
+        let name = if year == crate::calendar::YEAR { "answers.toml".to_owned() } else { format!("answers-{year}.toml") };
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(name)
+    }
+
+    /// Reads an answers file, treating a missing file as empty.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[must_use]
+    pub fn expected(&self, day: u8, part: u8) -> Option<&str> {
+        self.lookup(day, Input::Real, part)
+    }
+
+    /// The expected answer for example `example` (1 for `dayN.example.txt`).
+    #[must_use]
+    pub fn expected_example(&self, day: u8, example: u8, part: u8) -> Option<&str> {
+        self.lookup(day, Input::Example(example), part)
+    }
+
+    fn lookup(&self, day: u8, input: Input, part: u8) -> Option<&str> {
+        let slot = self.0.get(&(day, input))?.get(usize::from(part).checked_sub(1)?)?;
+        slot.as_deref()
+    }
+
+    /// Checks `answer` against the real input's expected answer, or example `example`'s.
+    #[must_use]
+    pub fn check(&self, day: u8, example: Option<u8>, part: u8, answer: &Answer) -> Check {
+        let input = example.map_or(Input::Real, Input::Example);
+        match self.lookup(day, input, part) {
+            None => Check::Unknown,
+            Some(expected) if answer == expected => Check::Pass,
+            Some(expected) => Check::Fail { expected: expected.to_owned() },
+        }
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// `example` is example 1, `example2` example 2 and so on.
+fn example_number(key: &str) -> Option<u8> {
+    match key.strip_prefix("example")? {
+        "" => Some(1),
+        n => n.parse().ok().filter(|&n| n > 0),
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = io::Error;
+
+    fn from_str(text: &str) -> io::Result<Self> {
+        let days: BTreeMap<String, BTreeMap<String, Entry>> =
+            toml::from_str(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut answers = BTreeMap::new();
+        for (day, entries) in days {
+            let day: u8 = day.parse().map_err(|_| invalid(format!("invalid day `{day}`")))?;
+            for (key, entry) in entries {
+                match (key.as_str(), entry) {
+                    ("part1", Entry::Part(expected)) => {
+                        answers.entry((day, Input::Real)).or_insert([None, None])[0] = Some(expected.to_string());
+                    }
+                    ("part2", Entry::Part(expected)) => {
+                        answers.entry((day, Input::Real)).or_insert([None, None])[1] = Some(expected.to_string());
+                    }
+                    (key, Entry::Example(example)) => {
+                        let n = example_number(key).ok_or_else(|| invalid(format!("invalid key `{key}` for day {day}")))?;
+                        let part = |expected: Option<Expected>| expected.map(|e| e.to_string());
+                        answers.insert((day, Input::Example(n)), [part(example.part1), part(example.part2)]);
+                    }
+                    (key, Entry::Part(_)) => return Err(invalid(format!("invalid key `{key}` for day {day}"))),
+                }
+            }
+        }
+        Ok(Self(answers))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{
+        answers::{Answers, Check},
+        solution::Answer,
+    };
+
+    const TOML: &str = "[1]\npart1 = 3\npart2 = \"6\"\nexample = { part1 = 1 }\nexample2 = { part2 = 2 }\n\n[2]\npart1 = 10\n";
+
+    #[test]
+    fn expected_answers_are_read() {
+        let answers: Answers = TOML.parse().unwrap();
+        assert_eq!(answers.expected(1, 1), Some("3"));
+        assert_eq!(answers.expected(1, 2), Some("6"));
+        assert_eq!(answers.expected(2, 2), None);
+        assert_eq!(answers.expected(3, 1), None);
+        assert_eq!(answers.expected(1, 0), None);
+    }
+
+    #[test]
+    fn example_answers_are_read() {
+        let answers: Answers = TOML.parse().unwrap();
+        assert_eq!(answers.expected_example(1, 1, 1), Some("1"));
+        assert_eq!(answers.expected_example(1, 1, 2), None);
+        assert_eq!(answers.expected_example(1, 2, 2), Some("2"));
+        assert_eq!(answers.expected_example(2, 1, 1), None);
+        assert_eq!(answers.check(1, Some(1), 1, &Answer::U64(1)), Check::Pass);
+        assert_eq!(answers.check(1, Some(2), 2, &Answer::U64(3)), Check::Fail { expected: "2".to_owned() });
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        assert!("[one]\npart1 = 3\n".parse::<Answers>().is_err());
+        assert!("[1]\npart3 = 3\n".parse::<Answers>().is_err());
+        assert!("[1\n".parse::<Answers>().is_err());
+        assert!("[1]\nexample0 = { part1 = 3 }\n".parse::<Answers>().is_err());
+        assert!("[1]\nexample = { part3 = 3 }\n".parse::<Answers>().is_err());
+        assert!("[1]\npart1 = { part1 = 3 }\n".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn answers_are_checked() {
+        let answers: Answers = TOML.parse().unwrap();
+        assert_eq!(answers.check(1, None, 1, &Answer::U64(3)), Check::Pass);
+        assert_eq!(answers.check(1, None, 2, &Answer::U64(7)), Check::Fail { expected: "6".to_owned() });
+        assert_eq!(answers.check(4, None, 1, &Answer::U64(3)), Check::Unknown);
+    }
+
+}
@@ -0,0 +1,204 @@
+use std::{
+    io, panic,
+    sync::mpsc::{self, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2025::{
+    answers::{Answers, Check},
+    days::{self, Day},
+    default_input,
+    solution::{Phase, RunOptions, RunResult},
+};
+use aoc_utils::{progress_bar, read_to_string};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Cell, Row, Table, TableState},
+};
+use rayon::prelude::*;
+
+/// How often the screen is redrawn, so running days' timers tick.
+const FRAME: Duration = Duration::from_millis(100);
+
+enum Status {
+    Queued,
+    Running(Instant),
+    Done(RunResult),
+    NoInput,
+}
+
+enum Update {
+    Started(usize),
+    Finished(usize, Option<RunResult>),
+}
+
+struct Dashboard {
+    days: Vec<&'static Day>,
+    statuses: Vec<Status>,
+    answers: Answers,
+    timeout: Option<Duration>,
+    table: TableState,
+}
+
+/// Runs every registered day in parallel under a live table of their progress, answers and
+/// timings, until `q` is pressed. `r` re-runs the selected day.
+pub fn dashboard(timeout: Option<Duration>) -> io::Result<()> {
+    let days = days::all();
+    let answers = Answers::load(&Answers::default_path()).unwrap_or_default();
+    let statuses = days.iter().map(|_| Status::Queued).collect();
+    let mut dashboard = Dashboard { days, statuses, answers, timeout, table: TableState::default().with_selected(0) };
+    progress_bar::set_enabled(false);
+
+    let mut terminal = ratatui::try_init()?;
+    silence_worker_panics();
+    let (sender, receiver) = mpsc::channel();
+    let (all, options) = (dashboard.days.clone(), dashboard.options());
+    let everything = sender.clone();
+    thread::spawn(move || {
+        all.par_iter().enumerate().for_each_with(everything, |sender, (i, day)| solve(i, day, &options, sender));
+    });
+
+    let result = dashboard.run(&mut terminal, &receiver, &sender);
+    ratatui::restore();
+    result
+}
+
+/// Solutions' panics are caught and shown as a failed day, so keep their messages from tearing
+/// up the screen. Panics on the main thread still restore the terminal and print as usual.
+fn silence_worker_panics() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() == Some("main") {
+            hook(info);
+        }
+    }));
+}
+
+fn solve(i: usize, day: &Day, options: &RunOptions, sender: &Sender<Update>) {
+    let _ = sender.send(Update::Started(i));
+    let result = read_to_string(default_input(day.number)).ok().map(|input| (day.run)(&input, &[1, 2], options));
+    let _ = sender.send(Update::Finished(i, result));
+}
+
+impl Dashboard {
+    fn options(&self) -> RunOptions {
+        RunOptions { timeout: self.timeout, ..RunOptions::default() }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal, receiver: &mpsc::Receiver<Update>, sender: &Sender<Update>) -> io::Result<()> {
+        loop {
+            for update in receiver.try_iter() {
+                match update {
+                    Update::Started(i) => self.statuses[i] = Status::Running(Instant::now()),
+                    Update::Finished(i, result) => self.statuses[i] = result.map_or(Status::NoInput, Status::Done),
+                }
+            }
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(FRAME)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else { continue };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.table.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+                KeyCode::Char('r') | KeyCode::Enter => self.rerun(sender),
+                _ => {}
+            }
+        }
+    }
+
+    /// Queues the selected day again, unless it is already queued or running.
+    fn rerun(&mut self, sender: &Sender<Update>) {
+        let Some(i) = self.table.selected().filter(|&i| i < self.days.len()) else { return };
+        if matches!(self.statuses[i], Status::Queued | Status::Running(_)) {
+            return;
+        }
+        self.statuses[i] = Status::Queued;
+        let (day, options, sender) = (self.days[i], self.options(), sender.clone());
+        thread::spawn(move || solve(i, day, &options, &sender));
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, footer_area] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let header = ["Day", "Status", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total"]
+            .map(|title| Cell::from(Line::from(title).right_aligned()));
+        let rows = self.days.iter().zip(&self.statuses).map(|(day, status)| self.row(day.number, status));
+        let widths = [4, 12, 16, 16, 10, 10, 10, 10].map(Constraint::Length);
+        let table = Table::new(rows, widths)
+            .header(Row::new(header).style(Style::new().add_modifier(Modifier::BOLD)))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let done: Vec<&RunResult> =
+            self.statuses.iter().filter_map(|status| if let Status::Done(result) = status { Some(result) } else { None }).collect();
+        let total: Duration = done.iter().map(|result| result.total()).sum();
+        let compiled_out = days::compiled_out().len();
+        let skipped = if compiled_out == 0 { String::new() } else { format!(", {compiled_out} compiled out") };
+        let footer = format!(
+            "{}/{} done{skipped}, {total:.2?} in total   ↑/↓ select   r re-run   q quit",
+            done.len(),
+            self.days.len()
+        );
+        frame.render_widget(Line::from(footer).style(Style::new().fg(Color::DarkGray)), footer_area);
+    }
+
+    fn row(&self, day: u8, status: &Status) -> Row<'static> {
+        let right = |text: String| Cell::from(Line::from(text).right_aligned());
+        let (label, colour) = match status {
+            Status::Queued => ("queued".to_owned(), Color::DarkGray),
+            Status::Running(start) => (format!("running {:.1?}", start.elapsed()), Color::Yellow),
+            Status::NoInput => ("no input".to_owned(), Color::DarkGray),
+            Status::Done(result) if self.failed(day, result) => ("failed".to_owned(), Color::Red),
+            Status::Done(_) => ("done".to_owned(), Color::Green),
+        };
+        let mut cells = vec![right(day.to_string()), Cell::from(Line::from(label).right_aligned().style(Style::new().fg(colour)))];
+
+        if let Status::Done(result) = status {
+            for part in [1, 2] {
+                cells.push(self.answer(day, result, part));
+            }
+            let parsed = result.panicked != Some(Phase::Parse) && result.timed_out != Some(Phase::Parse);
+            cells.push(right(if parsed { format!("{:.2?}", result.parse_elapsed) } else { String::new() }));
+            for part in [1, 2] {
+                let solved = result.parts.iter().find(|solved| solved.part == part);
+                cells.push(right(solved.map_or_else(String::new, |solved| format!("{:.2?}", solved.elapsed))));
+            }
+            cells.push(right(format!("{:.2?}", result.total())));
+        }
+        Row::new(cells)
+    }
+
+    fn answer(&self, day: u8, result: &RunResult, part: u8) -> Cell<'static> {
+        let failed = |phase: Phase| phase == Phase::Parse || phase == Phase::Part(part);
+        let (text, colour) = match result.parts.iter().find(|solved| solved.part == part) {
+            Some(solved) => match self.answers.check(day, None, part, &solved.answer) {
+                Check::Pass => (solved.answer.summary(), Color::Green),
+                Check::Fail { .. } => (solved.answer.summary(), Color::Red),
+                Check::Unknown => (solved.answer.summary(), Color::Reset),
+            },
+            None if result.panicked.is_some_and(failed) => ("panicked".to_owned(), Color::Red),
+            None if result.timed_out.is_some_and(failed) => ("timed out".to_owned(), Color::Red),
+            None => (String::new(), Color::Reset),
+        };
+        Cell::from(Line::from(text).right_aligned().style(Style::new().fg(colour)))
+    }
+
+    /// Whether the run crashed, timed out, or gave an answer `answers.toml` says is wrong.
+    fn failed(&self, day: u8, result: &RunResult) -> bool {
+        let wrong = |part: &aoc2025::solution::PartResult| {
+            matches!(self.answers.check(day, None, part.part, &part.answer), Check::Fail { .. })
+        };
+        result.panicked.is_some() || result.timed_out.is_some() || result.parts.iter().any(wrong)
+    }
+}
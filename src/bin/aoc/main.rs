@@ -0,0 +1,1387 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc2025::{
+    answers::{Answers, Check},
+    calendar, cli,
+    config::{self, Config, DayConfig},
+    days, default_input, logging,
+    progress::Progress,
+    report::{DayReport, Report},
+    solution::{Answer, PartResult, Phase, RunOptions, RunResult, Stats},
+    style::{self, dim, green, red},
+};
+use aoc_utils::{progress_bar, read_to_string, read_to_string_or_stdin};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use rayon::prelude::*;
+use serde::Serialize;
+
+#[cfg(feature = "tui")]
+mod dashboard;
+mod scaffold;
+mod watch;
+
+#[cfg(all(feature = "alloc-stats", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOCATOR: aoc2025::memory::CountingAllocator = aoc2025::memory::CountingAllocator;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOCATOR: dhat::Alloc = dhat::Alloc;
+
+/// Runs, fetches and submits Advent of Code solutions.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print without colours, as when `NO_COLOR` is set or stdout isn't a terminal
+    #[arg(long, global = true)]
+    no_color: bool,
+    /// The event to work on [default: `AOC_YEAR`, `year` from .aoc.toml, or 2025]
+    #[arg(long, global = true, value_parser = clap::value_parser!(i32).range(2015..))]
+    year: Option<i32>,
+}
+
+#[derive(Debug, PartialEq, Subcommand)]
+enum Command {
+    /// Solve a day, or every day with --all
+    Run(RunArgs),
+    /// Store your adventofcode.com session cookie in the OS keyring
+    Login,
+    /// Remove the session cookie stored by `aoc login`
+    Logout,
+    /// Download a day's input once it has unlocked
+    Fetch {
+        #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+        day: u8,
+    },
+    /// Scaffold a new day from the template
+    New {
+        #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+        day: u8,
+    },
+    /// Print a day's puzzle description
+    #[command(name = "desc", visible_alias = "open")]
+    Describe {
+        #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+        day: u8,
+    },
+    /// Time every implementation of a day on the same input and check that they agree
+    Compare(CompareArgs),
+    /// Solve a part and submit the answer
+    Submit(SubmitArgs),
+    /// Check every day against answers.toml
+    Verify,
+    /// Run every day under a live dashboard, re-running days on demand
+    #[command(visible_alias = "tui")]
+    Dashboard {
+        /// Give up on a part that runs longer than this many seconds
+        #[arg(long, value_name = "SECS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Run every day and write the answers and timings to a markdown or HTML report
+    Report {
+        /// Write HTML rather than markdown (implied by an .html output)
+        #[arg(long)]
+        html: bool,
+        /// Where to write it [default: report.md, or report.html with --html]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Give up on a part that runs longer than this many seconds
+        #[arg(long, value_name = "SECS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Re-run a day whenever its source or input changes
+    Watch {
+        #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+        day: u8,
+    },
+    /// Show the time until the next puzzle unlocks
+    Countdown {
+        /// Wait for the unlock, then fetch the input
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Show a private leaderboard's stars and scores
+    Leaderboard {
+        /// The leaderboard id [default: `leaderboard` from .aoc.toml]
+        id: Option<u64>,
+    },
+    /// Show which days are solved, with their best times
+    Status,
+    /// Print a tab-completion script for the given shell
+    Completions { shell: Shell },
+}
+
+#[derive(Debug, Default, PartialEq, clap::Args)]
+#[allow(clippy::struct_excessive_bools)]
+struct RunArgs {
+    /// The day to solve [default: today's puzzle, during the event]
+    #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+    day: Option<u8>,
+    /// Solve every registered day and print a summary table
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    #[command(flatten)]
+    common: cli::Args,
+    /// Solve every file in this directory and print a table of answers and timings
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["all", "input", "input_file", "example", "bench_runs", "profile", "heap_profile"])]
+    input_dir: Option<PathBuf>,
+    /// Untimed runs before the measured one
+    #[arg(long)]
+    warmup: Option<u32>,
+    /// Give up on a part that runs longer than this many seconds
+    #[arg(long, value_name = "SECS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Time this many runs and print min/median/mean/stddev of each phase
+    #[arg(long, value_name = "N", conflicts_with_all = ["all", "json"], value_parser = clap::value_parser!(u32).range(1..))]
+    bench_runs: Option<u32>,
+    /// Write a flamegraph of each phase, e.g. flamegraph-day1-part2.svg (needs the `profile` feature)
+    #[arg(long, conflicts_with = "all")]
+    profile: bool,
+    /// Record every allocation with dhat and write dhat-heap-dayN.json (needs the `dhat-heap` feature)
+    #[arg(long, conflicts_with = "all")]
+    heap_profile: bool,
+    /// Print one JSON object per solved part
+    #[arg(long, overrides_with = "no_json")]
+    json: bool,
+    /// Print the usual summary even if .aoc.toml asks for JSON
+    #[arg(long, overrides_with = "json")]
+    no_json: bool,
+    /// Print everything even if .aoc.toml asks for quiet output
+    #[arg(long, overrides_with = "quiet")]
+    no_quiet: bool,
+}
+
+#[derive(Debug, PartialEq, clap::Args)]
+struct CompareArgs {
+    #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+    day: u8,
+    /// Input file [default: inputs/dayN.txt]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+    /// Compare only this part
+    #[arg(short, long, value_parser = cli::part_parser())]
+    part: Option<u8>,
+    /// Timed runs of each implementation; the median is reported
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+}
+
+#[derive(Debug, PartialEq, clap::Args)]
+struct SubmitArgs {
+    #[arg(value_parser = cli::day_parser(), hide_possible_values = true)]
+    day: u8,
+    #[arg(value_parser = cli::part_parser())]
+    part: u8,
+    /// Input file [default: inputs/dayN.txt]
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("`{value}` is not a number of seconds"))
+}
+
+/// `day`, or today's puzzle while the event is running.
+fn today_or(day: Option<u8>) -> Result<u8, Failure> {
+    day.or_else(|| calendar::current_day(SystemTime::now())).ok_or_else(|| {
+        let year = calendar::year();
+        let message = format!("No day given, and no {year} puzzle unlocks today (December 1-{}, US/Eastern).", calendar::days_in(year));
+        Failure::from(message)
+    })
+}
+
+/// Describes a phase that ran out of time, e.g. "part 2 timed out".
+fn timed_out(phase: Phase) -> String {
+    match phase {
+        Phase::Parse => "parsing timed out".to_owned(),
+        Phase::Part(part) => format!("part {part} timed out"),
+    }
+}
+
+/// The exit codes scripts can rely on. Usage errors exit with 2, as clap does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Exit {
+    /// Anything else: bad configuration, network errors, ...
+    Error = 1,
+    MissingInput = 3,
+    /// The solution panicked while parsing its input.
+    ParseFailed = 4,
+    /// An answer differs from the one in `answers.toml`.
+    WrongAnswer = 5,
+    TimedOut = 6,
+    /// The solution panicked while solving a part.
+    Panicked = 7,
+}
+
+/// Why a command failed: what to print and how to exit.
+#[derive(Debug)]
+struct Failure {
+    exit: Exit,
+    message: String,
+}
+
+impl Failure {
+    fn new(exit: Exit, message: impl Into<String>) -> Self {
+        Self { exit, message: message.into() }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Self::new(Exit::Error, message)
+    }
+}
+
+impl From<&str> for Failure {
+    fn from(message: &str) -> Self {
+        Self::new(Exit::Error, message)
+    }
+}
+
+/// The failure, if any, of a run that panicked or timed out.
+fn outcome(day: u8, result: &RunResult, timeout: Option<Duration>) -> Result<(), Failure> {
+    match (result.panicked, result.timed_out) {
+        (Some(Phase::Parse), _) => Err(Failure::new(Exit::ParseFailed, format!("Day {day} failed to parse its input."))),
+        (Some(Phase::Part(part)), _) => Err(Failure::new(Exit::Panicked, format!("Day {day} part {part} panicked."))),
+        (None, Some(phase)) => {
+            let message = format!("Day {day} {} after {:.2?}.", timed_out(phase), timeout.unwrap_or_default());
+            Err(Failure::new(Exit::TimedOut, message))
+        }
+        (None, None) => Ok(()),
+    }
+}
+
+/// One solved part, as printed by `--json`.
+#[derive(Serialize)]
+struct JsonPart<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    duration_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<usize>,
+    input_path: &'a Path,
+}
+
+fn print_json(day: u8, path: &Path, result: &RunResult) -> Result<(), String> {
+    for part in &result.parts {
+        let duration_ns = u64::try_from(part.elapsed.as_nanos()).unwrap_or(u64::MAX);
+        let line = JsonPart {
+            day,
+            part: part.part,
+            answer: &part.answer,
+            duration_ns,
+            peak_bytes: part.peak_bytes,
+            input_path: path,
+        };
+        println!("{}", serde_json::to_string(&line).map_err(|e| e.to_string())?);
+    }
+    Ok(())
+}
+
+/// `, 1.50 MiB peak` when heap usage was measured.
+#[cfg(feature = "alloc-stats")]
+fn peak(bytes: Option<usize>) -> String {
+    bytes.map_or_else(String::new, |bytes| format!(", {} peak", aoc2025::memory::format_bytes(bytes)))
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+const fn peak(_bytes: Option<usize>) -> String {
+    String::new()
+}
+
+/// `args` with the unset options filled in from `.aoc.toml`.
+fn with_config(day: u8, args: RunArgs) -> RunArgs {
+    apply_config(args, config::get().day(day))
+}
+
+/// `args` with the options it leaves unset taken from `config`; `--no-json` and `--no-quiet`
+/// turn off what the config turns on.
+fn apply_config(mut args: RunArgs, config: DayConfig) -> RunArgs {
+    if args.common.example.is_none() {
+        args.common.input = args.common.input().cloned().or(config.input);
+    }
+    args.warmup = args.warmup.or(config.warmup);
+    args.json = !args.no_json && (args.json || config.json.unwrap_or(false));
+    args.common.quiet = !args.no_quiet && (args.common.quiet || config.quiet.unwrap_or(false));
+    args
+}
+
+fn find_day(day: u8) -> Result<&'static days::Day, Failure> {
+    days::find(day).ok_or_else(|| missing_day(day))
+}
+
+/// Why `day` can't be run: its `dayN` feature left it out of this build, or it isn't written yet.
+fn missing_day(day: u8) -> Failure {
+    if days::compiled_out().contains(&day) {
+        return Failure::from(format!("Day {day} is compiled out; rebuild with `--features day{day}` (or `full`) to run it."));
+    }
+    Failure::from(format!("Day {day} has no registered {} solution.", calendar::year()))
+}
+
+/// `Day(s) 9, 17 compiled out ...` when commands that run every day had to leave some out.
+fn compiled_out_note() -> Option<String> {
+    let days: Vec<String> = days::compiled_out().iter().map(u8::to_string).collect();
+    (!days.is_empty()).then(|| format!("Day(s) {} compiled out; rebuild with `--features full` to include them.", days.join(", ")))
+}
+
+fn read_input(path: &Path) -> Result<String, Failure> {
+    read_to_string_or_stdin(Some(path)).map_err(|e| {
+        let exit = if e.kind() == io::ErrorKind::NotFound { Exit::MissingInput } else { Exit::Error };
+        Failure::new(exit, format!("Failed to read input: {e}"))
+    })
+}
+
+fn solve(day: u8, args: &RunArgs) -> Result<(&'static days::Day, PathBuf, String, RunResult), Failure> {
+    let day = find_day(day)?;
+    if args.profile && !cfg!(feature = "profile") {
+        return Err("Profiling requires the `profile` feature.".into());
+    }
+    if args.heap_profile && !cfg!(feature = "dhat-heap") {
+        return Err("Heap profiling requires the `dhat-heap` feature.".into());
+    }
+
+    let path = args.common.input_path(day.number);
+    let input = read_input(&path)?;
+
+    progress_bar::set_enabled(!args.json);
+    let options = run_options(args);
+    let result = {
+        let _heap_profiler = args.heap_profile.then(|| heap_profiler(day.number, args.common.part));
+        tracing::info_span!("day", day = day.number).in_scope(|| (day.run)(&input, &args.common.parts(), &options))
+    };
+    Ok((day, path, input, result))
+}
+
+/// How to time a run: with `--warmup`, `--timeout` and `--profile` as given or configured.
+fn run_options(args: &RunArgs) -> RunOptions {
+    RunOptions { warmup: args.warmup.unwrap_or(0), timeout: args.timeout, profile: args.profile }
+}
+
+/// Records allocations until dropped, then writes them to `dhat-heap-dayN.json` (or
+/// `dhat-heap-dayN-partM.json` for a single part) for dhat's viewer.
+#[cfg(feature = "dhat-heap")]
+fn heap_profiler(day: u8, part: Option<u8>) -> dhat::Profiler {
+    let suffix = part.map_or_else(String::new, |part| format!("-part{part}"));
+    dhat::Profiler::builder().file_name(format!("dhat-heap-day{day}{suffix}.json")).build()
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+const fn heap_profiler(_day: u8, _part: Option<u8>) {}
+
+/// Whether the run read the day's own input, its default or the one `.aoc.toml` names, or one of
+/// its examples, which are the only inputs `answers.toml` has answers for.
+fn own_input(day: u8, args: &RunArgs) -> bool {
+    args.common.input().is_none_or(|path| *path == default_input(day) || Some(path) == config::get().day(day).input.as_ref())
+}
+
+/// How `answer` compares with `answers.toml`, [`Check::Unknown`] for someone else's input.
+fn expected(answers: &Answers, day: u8, args: &RunArgs, part: u8, answer: &Answer) -> Check {
+    if !own_input(day, args) {
+        return Check::Unknown;
+    }
+    answers.check(day, args.common.example, part, answer)
+}
+
+/// ` ok` or ` FAIL, expected ...` when `answers.toml` knows the answer.
+fn check(answers: &Answers, day: u8, args: &RunArgs, part: u8, answer: &Answer) -> String {
+    match expected(answers, day, args, part, answer) {
+        Check::Pass => format!(" {}", green("ok")),
+        Check::Fail { expected } => format!(" {}", red(format!("FAIL, expected {expected}"))),
+        Check::Unknown => String::new(),
+    }
+}
+
+/// Marks `parts` solved in `.aoc/progress.json`, warning rather than failing if it can't be saved.
+fn record_progress<'a>(day: u8, parts: impl IntoIterator<Item = &'a PartResult>) {
+    let path = Progress::default_path();
+    let saved = Progress::load(&path).and_then(|mut progress| {
+        for part in parts {
+            progress.record(day, part.part, &part.answer.to_string(), Some(part.elapsed), SystemTime::now());
+        }
+        progress.save(&path)
+    });
+    if let Err(e) = saved {
+        tracing::warn!("failed to update {}: {e}", path.display());
+    }
+}
+
+/// Fails with [`Exit::WrongAnswer`] if any part differs from `answers.toml`.
+fn wrong_answers(answers: &Answers, day: u8, args: &RunArgs, result: &RunResult) -> Result<(), Failure> {
+    let wrong: Vec<String> = result
+        .parts
+        .iter()
+        .filter(|part| matches!(expected(answers, day, args, part.part, &part.answer), Check::Fail { .. }))
+        .map(|part| part.part.to_string())
+        .collect();
+    if wrong.is_empty() {
+        return Ok(());
+    }
+    Err(Failure::new(Exit::WrongAnswer, format!("Day {day} part {} gave the wrong answer.", wrong.join(" and "))))
+}
+
+/// Fails if `.aoc.toml` asks for JSON from a `--bench-runs` run, which clap only rules out on the
+/// command line.
+fn plain_bench(args: &RunArgs) -> Result<(), Failure> {
+    if args.bench_runs.is_some() && args.json {
+        return Err("--bench-runs can't print JSON; pass --no-json to override .aoc.toml.".into());
+    }
+    Ok(())
+}
+
+fn run(day: u8, args: &RunArgs) -> Result<(), Failure> {
+    if let Some(dir) = &args.input_dir {
+        return run_dir(day, args, dir);
+    }
+    plain_bench(args)?;
+    let (day, path, input, result) = solve(day, args)?;
+    let answers = Answers::load(&Answers::default_path()).unwrap_or_default();
+    if args.common.example.is_none() {
+        let passed = |part: &&PartResult| expected(&answers, day.number, args, part.part, &part.answer) == Check::Pass;
+        record_progress(day.number, result.parts.iter().filter(passed));
+    }
+    if let Some(runs) = args.bench_runs {
+        return bench(day, args, &input, result, runs);
+    }
+    if args.json {
+        print_json(day.number, &path, &result)?;
+    } else if args.common.quiet {
+        for part in &result.parts {
+            if args.common.time {
+                println!("{} ({:.2?}{})", part.answer, part.elapsed, peak(part.peak_bytes));
+            } else {
+                println!("{}", part.answer);
+            }
+        }
+    } else {
+        print_run(day, args, &answers, &result);
+    }
+    if args.profile {
+        write_flamegraphs(day.number, &result)?;
+    }
+    outcome(day.number, &result, args.timeout)?;
+    wrong_answers(&answers, day.number, args, &result)
+}
+
+/// Writes `flamegraph-dayN-parse.svg` and `flamegraph-dayN-partM.svg` for each phase that was sampled.
+fn write_flamegraphs(day: u8, result: &RunResult) -> Result<(), Failure> {
+    let parts = result.parts.iter().map(|part| (format!("part{}", part.part), &part.flamegraph));
+    for (phase, flamegraph) in std::iter::once(("parse".to_owned(), &result.parse_flamegraph)).chain(parts) {
+        let path = PathBuf::from(format!("flamegraph-day{day}-{phase}.svg"));
+        match flamegraph {
+            Some(svg) => {
+                std::fs::write(&path, svg).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+                eprintln!("Wrote {}", path.display());
+            }
+            None => eprintln!("Day {day} {phase} finished too quickly to sample."),
+        }
+    }
+    Ok(())
+}
+
+fn print_run(day: &days::Day, args: &RunArgs, answers: &Answers, result: &RunResult) {
+    if result.panicked == Some(Phase::Parse) || result.timed_out == Some(Phase::Parse) {
+        return;
+    }
+    let parse = dim(format!("{:.2?}{}", result.parse_elapsed, peak(result.parse_peak_bytes)));
+    println!("Day {} parsed in {parse}", day.number);
+    for part in &result.parts {
+        let check = check(answers, day.number, args, part.part, &part.answer);
+        let (answer, timing) = (green(&part.answer), dim(format!("({:.2?}{})", part.elapsed, peak(part.peak_bytes))));
+        println!("Day {} part {}: {answer} {timing}{check}", day.number, part.part);
+    }
+    println!("Day {} total {:.2?}", day.number, dim(result.total()));
+}
+
+/// Repeats the run until there are `runs` timings, `first` included, and prints each phase's
+/// statistics. Stops at the first phase to time out.
+fn bench(day: &days::Day, args: &RunArgs, input: &str, first: RunResult, runs: u32) -> Result<(), Failure> {
+    let options = run_options(args);
+    outcome(day.number, &first, args.timeout)?;
+    let mut results = vec![first];
+    for _ in 1..runs {
+        let result = (day.run)(input, &args.common.parts(), &options);
+        outcome(day.number, &result, args.timeout)?;
+        results.push(result);
+    }
+
+    let describe = |samples: Vec<Duration>| {
+        Stats::of(&samples).map_or_else(String::new, |stats| {
+            let summary = format!(
+                "min {:.2?}, median {:.2?}, mean {:.2?}, σ {:.2?} over {} runs",
+                stats.min, stats.median, stats.mean, stats.stddev, stats.runs
+            );
+            dim(summary).to_string()
+        })
+    };
+    let answers = Answers::load(&Answers::default_path()).unwrap_or_default();
+    println!("Day {} parse: {}", day.number, describe(results.iter().map(|result| result.parse_elapsed).collect()));
+    for (i, part) in results[0].parts.iter().enumerate() {
+        let samples = results.iter().map(|result| result.parts[i].elapsed).collect();
+        let check = check(&answers, day.number, args, part.part, &part.answer);
+        println!("Day {} part {}: {}{check} ({})", day.number, part.part, green(&part.answer), describe(samples));
+    }
+    println!("Day {} total: {}", day.number, describe(results.iter().map(RunResult::total).collect()));
+    wrong_answers(&answers, day.number, args, &results[0])
+}
+
+/// Runs every registered day against its real input, in parallel, and prints a table of answers
+/// and timings in day order. JSON lines and progress on stderr are streamed as days finish.
+fn run_all(json: bool, timeout: Option<Duration>) -> Result<(), Failure> {
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let mut finished = BTreeMap::new();
+    let progress = !json && io::stderr().is_terminal();
+    // Days run side by side, so their bars would only fight over stderr.
+    progress_bar::set_enabled(false);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            days::all().par_iter().for_each_with(sender, |sender, day| {
+                let path = default_input(day.number);
+                let result = read_to_string(&path)
+                    .ok()
+                    .map(|input| (day.run)(&input, &[1, 2], &RunOptions { timeout, ..RunOptions::default() }));
+                let _ = sender.send((day.number, path, result));
+            });
+        });
+
+        for (day, path, result) in receiver {
+            if let Some(Err(failure)) = result.as_ref().map(|result| outcome(day, result, timeout)) {
+                eprintln!("{}", red(failure.message));
+            }
+            match &result {
+                Some(result) if json => print_json(day, &path, result)?,
+                Some(result) if progress => eprintln!("Day {day} finished in {:.2?}", dim(result.total())),
+                _ => {}
+            }
+            finished.insert(day, result);
+        }
+        Ok::<_, String>(())
+    })?;
+    let failures: Vec<Failure> = finished
+        .iter()
+        .filter_map(|(&day, result)| result.as_ref().and_then(|result| outcome(day, result, timeout).err()))
+        .collect();
+    if json {
+        if let Some(note) = compiled_out_note() {
+            eprintln!("{}", dim(note));
+        }
+        return unfinished(&failures, "day(s)");
+    }
+
+    let mut rows: Vec<u8> = finished.keys().copied().chain(days::compiled_out()).collect();
+    rows.sort_unstable();
+    println!("{:>3}  {}", "Day", table_header());
+    let mut total = Duration::ZERO;
+    for day in rows {
+        match finished.remove(&day) {
+            Some(Some(result)) => {
+                total += result.total();
+                println!("{day:>3}  {}", table_row(&result));
+            }
+            Some(None) => println!("{day:>3}  (no input)"),
+            None => println!("{day:>3}  {}", dim("(compiled out)")),
+        }
+    }
+
+    println!("Total: {:.2?} ({:.2?} wall clock)", dim(total), dim(start.elapsed()));
+    unfinished(&failures, "day(s)")
+}
+
+/// Solves `day` against every file in `dir`, in name order, and prints a table of answers and
+/// timings. Hidden files are skipped.
+fn run_dir(day: u8, args: &RunArgs, dir: &Path) -> Result<(), Failure> {
+    let day = find_day(day)?;
+    let mut inputs: Vec<(String, PathBuf)> = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read {}: {e}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path())))
+        .filter(|(name, path)| !name.starts_with('.') && path.is_file())
+        .collect();
+    inputs.sort();
+    if inputs.is_empty() {
+        return Err(Failure::new(Exit::MissingInput, format!("There are no inputs in {}.", dir.display())));
+    }
+
+    let options = RunOptions { warmup: args.warmup.unwrap_or(0), timeout: args.timeout, ..RunOptions::default() };
+    let width = inputs.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0).max("Input".len());
+    if !args.json {
+        println!("{:<width$}  {}", "Input", table_header());
+    }
+    let mut failures = Vec::new();
+    for (name, path) in &inputs {
+        let input = match read_to_string(path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}", red(format!("Failed to read input: {e}")));
+                failures.push(Failure::new(Exit::Error, name.clone()));
+                continue;
+            }
+        };
+        let result = tracing::info_span!("input", name).in_scope(|| (day.run)(&input, &args.common.parts(), &options));
+        if let Err(failure) = outcome(day.number, &result, args.timeout) {
+            eprintln!("{}", red(format!("{name}: {}", failure.message)));
+            failures.push(failure);
+        }
+        if args.json {
+            print_json(day.number, path, &result)?;
+        } else {
+            println!("{name:<width$}  {}", table_row(&result));
+        }
+    }
+
+    unfinished(&failures, "input(s)")
+}
+
+/// Fails with the first failure's exit code if anything in a batch failed.
+fn unfinished(failures: &[Failure], what: &str) -> Result<(), Failure> {
+    failures.first().map_or(Ok(()), |first| Err(Failure::new(first.exit, format!("{} {what} did not finish.", failures.len()))))
+}
+
+/// The column headings of the `--all` and `--input-dir` tables, after the first.
+fn table_header() -> String {
+    format!("{:>16}  {:>16}  {:>10}  {:>10}  {:>10}", "Part 1", "Part 2", "Parse", "Part 1", "Part 2")
+}
+
+/// A run's answers and timings as table cells; parts that weren't run are left blank.
+fn table_row(result: &RunResult) -> String {
+    let answer = |n: u8| {
+        let failed = |phase: &Phase| *phase == Phase::Parse || *phase == Phase::Part(n);
+        match (result.parts.iter().find(|part| part.part == n), result.panicked.filter(failed), result.timed_out.filter(failed)) {
+            (Some(part), _, _) => green(part.answer.summary()),
+            (None, Some(_), _) => red("panicked".to_owned()),
+            (None, None, Some(_)) => red("timed out".to_owned()),
+            (None, None, None) => green(String::new()),
+        }
+    };
+    let elapsed = |n: u8| {
+        let part = result.parts.iter().find(|part| part.part == n);
+        dim(part.map_or_else(String::new, |part| format!("{:.2?}", part.elapsed)))
+    };
+    let parsed = result.panicked != Some(Phase::Parse) && result.timed_out != Some(Phase::Parse);
+    let parse = dim(if parsed { format!("{:.2?}", result.parse_elapsed) } else { String::new() });
+    format!("{:>16}  {:>16}  {parse:>10}  {:>10}  {:>10}", answer(1), answer(2), elapsed(1), elapsed(2))
+}
+
+/// Runs each implementation of a day `runs` times on the same input and prints their median
+/// timings and speedup over the main one, failing if any answer differs from the main one's.
+fn compare(args: &CompareArgs) -> Result<(), Failure> {
+    let variants = days::variants(args.day);
+    if variants.is_empty() {
+        return Err(missing_day(args.day));
+    }
+    if variants.len() < 2 {
+        let message = format!(
+            "Day {} has nothing to compare; register another implementation with `aoc_day!({}, ..., variant = \"name\")`.",
+            args.day, args.day
+        );
+        return Err(message.into());
+    }
+    let path = args.input.clone().unwrap_or_else(|| default_input(args.day));
+    let input = read_input(&path)?;
+    let parts = args.part.map_or_else(|| vec![1, 2], |part| vec![part]);
+
+    let width = variants.iter().map(|day| day.name().len()).max().unwrap_or(0).max("Variant".len());
+    println!("{:<width$}  {}  {:>10}  {:>8}", "Variant", table_header(), "Total", "Speedup");
+    let mut main: Option<RunResult> = None;
+    let mut disagreements = 0;
+    for day in variants {
+        let mut runs = Vec::new();
+        for _ in 0..args.runs {
+            let result = (day.run)(&input, &parts, &RunOptions::default());
+            outcome(day.number, &result, None).map_err(|f| Failure::new(f.exit, format!("{}: {}", day.name(), f.message)))?;
+            runs.push(result);
+        }
+        let result = median_run(runs);
+        let speedup = main.as_ref().map_or(1.0, |main| main.total().as_secs_f64() / result.total().as_secs_f64());
+        let total = dim(format!("{:.2?}", result.total()));
+        println!("{:<width$}  {}  {total:>10}  {:>7.2}x", day.name(), table_row(&result), speedup);
+
+        for (part, expected) in result.parts.iter().zip(main.iter().flat_map(|main| &main.parts)) {
+            if part.answer != expected.answer {
+                let message = format!("{} part {} answered {}, but main answered {}.", day.name(), part.part, part.answer, expected.answer);
+                eprintln!("{}", red(message));
+                disagreements += 1;
+            }
+        }
+        main.get_or_insert(result);
+    }
+
+    if disagreements == 0 {
+        Ok(())
+    } else {
+        Err(Failure::new(Exit::WrongAnswer, format!("{disagreements} answer(s) differ from the main implementation's.")))
+    }
+}
+
+/// The first of `runs` (which all finished) with each phase's time replaced by its median.
+fn median_run(runs: Vec<RunResult>) -> RunResult {
+    let median = |samples: Vec<Duration>| Stats::of(&samples).map_or(Duration::ZERO, |stats| stats.median);
+    let parse_elapsed = median(runs.iter().map(|run| run.parse_elapsed).collect());
+    let elapsed: Vec<Duration> =
+        (0..runs[0].parts.len()).map(|i| median(runs.iter().map(|run| run.parts[i].elapsed).collect())).collect();
+    let mut result = runs.into_iter().next().unwrap_or_default();
+    result.parse_elapsed = parse_elapsed;
+    for (part, elapsed) in result.parts.iter_mut().zip(elapsed) {
+        part.elapsed = elapsed;
+    }
+    result
+}
+
+/// Runs every registered day against its real input and writes a report of the answers, how they
+/// compare with `answers.toml`, and the timings.
+fn report(html: bool, output: Option<PathBuf>, timeout: Option<Duration>) -> Result<(), Failure> {
+    let answers = Answers::load(&Answers::default_path()).map_err(|e| format!("Failed to read answers: {e}"))?;
+    let html = html || output.as_ref().is_some_and(|path| path.extension().is_some_and(|ext| ext == "html"));
+    let path = output.unwrap_or_else(|| PathBuf::from(if html { "report.html" } else { "report.md" }));
+
+    let days = days::all()
+        .into_iter()
+        .map(|day| {
+            let input = default_input(day.number);
+            let result = read_to_string(&input)
+                .ok()
+                .map(|text| (day.run)(&text, &[1, 2], &RunOptions { timeout, ..RunOptions::default() }));
+            let parts = result.iter().flat_map(|result| &result.parts);
+            let checks = parts.map(|part| answers.check(day.number, None, part.part, &part.answer)).collect();
+            // Reports get shared, so leave out where the crate happens to be checked out.
+            let input = input.strip_prefix(env!("CARGO_MANIFEST_DIR")).map_or_else(|_| input.clone(), Path::to_path_buf);
+            DayReport { day: day.number, input, result, checks }
+        })
+        .collect();
+    let report = Report { year: calendar::year(), days };
+
+    let text = if html { report.html() } else { report.markdown() };
+    std::fs::write(&path, text).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    let (verified, solved) = report.verified();
+    println!("Wrote {} ({verified} of {solved} answers verified).", path.display());
+    if let Some(note) = compiled_out_note() {
+        println!("{}", dim(note));
+    }
+    Ok(())
+}
+
+/// Runs every registered day against its real input and compares with `answers.toml`.
+fn verify() -> Result<(), Failure> {
+    let answers = Answers::load(&Answers::default_path()).map_err(|e| format!("Failed to read answers: {e}"))?;
+    let mut failures = 0;
+    let mut crashed = None;
+
+    for day in days::all() {
+        let path = default_input(day.number);
+        let Ok(input) = read_to_string(&path) else {
+            println!("Day {:>2}: skipped, no input at {}", day.number, path.display());
+            continue;
+        };
+
+        let result = (day.run)(&input, &[1, 2], &RunOptions::default());
+        record_progress(day.number, result.parts.iter().filter(|part| answers.check(day.number, None, part.part, &part.answer) == Check::Pass));
+        for part in &result.parts {
+            let status = match answers.check(day.number, None, part.part, &part.answer) {
+                Check::Pass => green("ok".to_owned()),
+                Check::Fail { expected } => {
+                    failures += 1;
+                    red(format!("FAIL, expected {expected}"))
+                }
+                Check::Unknown => dim("no expected answer".to_owned()),
+            };
+            println!("Day {:>2} part {}: {} ({status})", day.number, part.part, &part.answer);
+        }
+        if let Err(failure) = outcome(day.number, &result, None) {
+            println!("Day {:>2}: {}", day.number, red(&failure.message));
+            crashed = crashed.or(Some(failure.exit));
+        }
+    }
+    for day in days::compiled_out() {
+        println!("Day {day:>2}: skipped, compiled out (enable the `day{day}` feature)");
+    }
+
+    match (failures, crashed) {
+        (0, None) => Ok(()),
+        (0, Some(exit)) => Err(Failure::new(exit, "A solution crashed during verification.")),
+        _ => Err(Failure::new(Exit::WrongAnswer, format!("{failures} part(s) failed verification."))),
+    }
+}
+
+/// Scaffolds `src/days/dayN.rs` with a stubbed `Solution` and registers it.
+fn new_day(day: u8) -> Result<(), String> {
+    if calendar::year() != calendar::YEAR {
+        return Err(format!(
+            "Scaffolding only creates {} days; register earlier events' days with `aoc_day!(year, day, Type)`.",
+            calendar::YEAR
+        ));
+    }
+    if !(1..=calendar::LAST_DAY).contains(&day) {
+        return Err(format!("Day {day} is outside the calendar."));
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let touched = scaffold::scaffold(root, &default_input(day), day).map_err(|e| format!("Failed to scaffold day {day}: {e}"))?;
+    for path in touched {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "tui")]
+fn dashboard(timeout: Option<Duration>) -> Result<(), String> {
+    dashboard::dashboard(timeout).map_err(|e| format!("The dashboard failed: {e}"))
+}
+
+#[cfg(not(feature = "tui"))]
+fn dashboard(_timeout: Option<Duration>) -> Result<(), String> {
+    Err("The dashboard requires the `tui` feature.".to_owned())
+}
+
+/// Watches the day's module, the shared library code and the input, re-running on every change.
+fn watch_day(day: u8) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let paths = [root.join("src"), root.join("answers.toml"), default_input(day)];
+    watch::watch(day, &paths).map_err(|e| format!("Failed to run day {day}: {e}"))
+}
+
+/// Reads the session cookie from stdin and stores it in the OS keyring for `fetch`, `submit` and
+/// the rest.
+#[cfg(feature = "keyring")]
+fn login() -> Result<(), String> {
+    use std::io::Write;
+
+    if io::stdin().is_terminal() {
+        print!("Paste your adventofcode.com session cookie: ");
+        let _ = io::stdout().flush();
+    }
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).map_err(|e| format!("Failed to read the cookie: {e}"))?;
+    let cookie = aoc2025::credentials::cookie_value(&line).ok_or("No cookie was given.")?;
+    aoc2025::credentials::store(cookie).map_err(|e| format!("Failed to store the cookie in the keyring: {e}"))?;
+    println!("Stored the session cookie in the OS keyring.");
+    if std::env::var_os("AOC_SESSION").is_some_and(|session| !session.is_empty()) {
+        println!("{}", dim("AOC_SESSION is set, and is used instead while it is."));
+    }
+    Ok(())
+}
+
+#[cfg(feature = "keyring")]
+fn logout() -> Result<(), String> {
+    match aoc2025::credentials::delete() {
+        Ok(true) => println!("Removed the session cookie from the OS keyring."),
+        Ok(false) => println!("No session cookie was stored."),
+        Err(e) => return Err(format!("Failed to remove the cookie from the keyring: {e}")),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "keyring"))]
+fn login() -> Result<(), String> {
+    Err("Storing the session cookie requires the `keyring` feature.".to_owned())
+}
+
+#[cfg(not(feature = "keyring"))]
+fn logout() -> Result<(), String> {
+    login()
+}
+
+#[cfg(feature = "http")]
+fn fetch(day: u8) -> Result<(), String> {
+    use aoc2025::fetch::{Fetched, fetch_input};
+
+    match fetch_input(day).map_err(|e| format!("Failed to fetch day {day}: {e}"))? {
+        Fetched::Downloaded(path) => println!("Saved day {day} input to {}", path.display()),
+        Fetched::AlreadyPresent(path) => println!("Day {day} input already present at {}", path.display()),
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+fn fetch(_day: u8) -> Result<(), String> {
+    Err("Fetching requires the `http` feature.".to_owned())
+}
+
+/// `2d 03h 04m 05s`, dropping leading zero units.
+fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let (days, hours, minutes, seconds) = (seconds / 86_400, seconds / 3_600 % 24, seconds / 60 % 60, seconds % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{seconds}s"),
+        (0, 0, _) => format!("{minutes}m {seconds:02}s"),
+        (0, _, _) => format!("{hours}h {minutes:02}m {seconds:02}s"),
+        _ => format!("{days}d {hours:02}h {minutes:02}m {seconds:02}s"),
+    }
+}
+
+/// Prints the progress grid from `.aoc/progress.json`.
+fn status() -> Result<(), String> {
+    let progress = Progress::load(&Progress::default_path()).map_err(|e| format!("Failed to read progress: {e}"))?;
+    println!("{progress}");
+    Ok(())
+}
+
+/// Prints the time until the next unlock, ticking down and then fetching the input with `wait`.
+fn countdown(wait: bool) -> Result<(), String> {
+    let day = calendar::next_unlock(SystemTime::now())
+        .ok_or_else(|| format!("Every {} puzzle has unlocked.", calendar::year()))?;
+    let unlock = calendar::unlock_time(day);
+    let remaining = || unlock.duration_since(SystemTime::now()).unwrap_or_default();
+    if !wait {
+        println!("Day {day} unlocks in {}", format_countdown(remaining()));
+        return Ok(());
+    }
+
+    let live = io::stdout().is_terminal();
+    while !remaining().is_zero() {
+        if live {
+            print!("\rDay {day} unlocks in {}\x1b[K", format_countdown(remaining()));
+            io::Write::flush(&mut io::stdout()).map_err(|e| e.to_string())?;
+        }
+        // Sleep to the next whole second left, so the display ticks in step with the clock.
+        let left = remaining();
+        let tick = Duration::from_nanos(left.subsec_nanos().into());
+        thread::sleep(if tick.is_zero() { left.min(Duration::from_secs(1)) } else { tick });
+    }
+    if live {
+        println!();
+    }
+    fetch(day)
+}
+
+#[cfg(feature = "http")]
+fn leaderboard(id: Option<u64>) -> Result<(), String> {
+    let id = id
+        .or_else(|| config::get().leaderboard)
+        .ok_or("Give a leaderboard id, or set `leaderboard` in .aoc.toml.")?;
+    let board = aoc2025::leaderboard::leaderboard(id).map_err(|e| format!("Failed to get leaderboard {id}: {e}"))?;
+    print!("{board}");
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+#[allow(clippy::unnecessary_wraps)]
+fn leaderboard(_id: Option<u64>) -> Result<(), String> {
+    Err("Leaderboards require the `http` feature.".to_owned())
+}
+
+#[cfg(feature = "http")]
+fn describe(day: u8) -> Result<(), String> {
+    let parts = aoc2025::puzzle::description(day).map_err(|e| format!("Failed to get day {day}: {e}"))?;
+    println!("{}", parts.join("\n"));
+    Ok(())
+}
+
+#[cfg(not(feature = "http"))]
+fn describe(_day: u8) -> Result<(), String> {
+    Err("Downloading descriptions requires the `http` feature.".to_owned())
+}
+
+#[cfg(feature = "http")]
+fn submit(args: SubmitArgs) -> Result<(), Failure> {
+    use aoc2025::submit::{GuessLog, Verdict, submit_answer};
+
+    let common = cli::Args { input: args.input, part: Some(args.part), ..cli::Args::default() };
+    let run = with_config(args.day, RunArgs { day: Some(args.day), common, ..RunArgs::default() });
+    let (day, _, _, result) = solve(args.day, &run)?;
+    let part = result.parts.first().ok_or("No part was solved.")?;
+    if let Answer::Grid(_) = part.answer {
+        return Err(format!("Day {} part {} answered with ASCII art; submit the letters it spells by hand:\n{}", day.number, part.part, part.answer).into());
+    }
+    let answer = part.answer.to_string();
+    println!("Day {} part {}: submitting {answer}", day.number, part.part);
+
+    let log = GuessLog::load(&GuessLog::default_path()).map_err(|e| format!("Failed to read guess log: {e}"))?;
+    if let Some(warning) = log.bounds_warning(day.number, part.part, &answer)
+        && !confirm(&format!("Warning: {warning}. Submit anyway?"))
+    {
+        return Ok(());
+    }
+
+    let verdict = submit_answer(day.number, part.part, &answer).map_err(|e| format!("Failed to submit: {e}"))?;
+    if verdict == Verdict::Correct {
+        record_progress(day.number, [part]);
+    }
+    match verdict {
+        Verdict::Correct => println!("Correct!"),
+        Verdict::TooHigh => println!("Wrong: too high."),
+        Verdict::TooLow => println!("Wrong: too low."),
+        Verdict::Incorrect => println!("Wrong."),
+        Verdict::Wait(wait) => println!("Submitted too recently; wait {}s.", wait.as_secs()),
+        Verdict::WrongLevel => println!("This part is already solved or not yet unlocked."),
+        Verdict::PreviouslyRejected => println!("Not submitted: this answer was already rejected."),
+        Verdict::Unrecognized(text) => println!("Unrecognized response: {text}"),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "http")]
+fn confirm(question: &str) -> bool {
+    use std::io::Write;
+
+    print!("{question} [y/N] ");
+    let _ = std::io::stdout().flush();
+    let mut reply = String::new();
+    std::io::stdin().read_line(&mut reply).is_ok() && reply.trim().eq_ignore_ascii_case("y")
+}
+
+#[cfg(not(feature = "http"))]
+#[allow(clippy::needless_pass_by_value)]
+fn submit(_args: SubmitArgs) -> Result<(), Failure> {
+    Err("Submitting requires the `http` feature.".into())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if cli.no_color {
+        style::set_enabled(false);
+    }
+    if let Some(year) = cli.year {
+        calendar::set_year(year);
+    }
+    logging::init(match &cli.command {
+        Command::Run(args) => args.common.verbose,
+        _ => 0,
+    });
+    if let Err(e) = Config::load(&Config::default_path()) {
+        eprintln!("Invalid {}: {e}", Config::default_path().display());
+        return ExitCode::FAILURE;
+    }
+
+    let result = match cli.command {
+        Command::Run(args) if args.all => run_all(!args.no_json && (args.json || config::get().json.unwrap_or(false)), args.timeout),
+        Command::Run(args) => today_or(args.day).and_then(|day| run(day, &with_config(day, args))),
+        Command::Login => login().map_err(Failure::from),
+        Command::Logout => logout().map_err(Failure::from),
+        Command::Fetch { day } => fetch(day).map_err(Failure::from),
+        Command::New { day } => new_day(day).map_err(Failure::from),
+        Command::Describe { day } => describe(day).map_err(Failure::from),
+        Command::Compare(args) => compare(&args),
+        Command::Submit(args) => submit(args),
+        Command::Verify => verify(),
+        Command::Dashboard { timeout } => dashboard(timeout).map_err(Failure::from),
+        Command::Report { html, output, timeout } => report(html, output, timeout),
+        Command::Watch { day } => watch_day(day).map_err(Failure::from),
+        Command::Countdown { wait } => countdown(wait).map_err(Failure::from),
+        Command::Status => status().map_err(Failure::from),
+        Command::Leaderboard { id } => leaderboard(id).map_err(Failure::from),
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "aoc", &mut std::io::stdout());
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(failure) => {
+            eprintln!("{}", failure.message);
+            ExitCode::from(failure.exit as u8)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::{path::PathBuf, time::Duration};
+
+    use aoc2025::{answers::{Answers, Check}, cli, config::DayConfig, default_input};
+    use clap::{CommandFactory, Parser};
+    use clap_complete::Shell;
+
+    use aoc2025::solution::{Answer, Phase, RunResult};
+
+    use crate::{Cli, Command, CompareArgs, Exit, RunArgs, SubmitArgs, apply_config, expected, format_countdown, outcome, own_input, plain_bench};
+
+    fn parse(args: &str) -> Option<Command> {
+        Cli::try_parse_from(std::iter::once("aoc").chain(args.split_whitespace())).ok().map(|cli| cli.command)
+    }
+
+    fn run(day: u8, common: cli::Args) -> Command {
+        Command::Run(RunArgs { day: Some(day), common, ..RunArgs::default() })
+    }
+
+    #[test]
+    fn definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn run_with_options() {
+        assert_eq!(parse("run 1"), Some(run(1, cli::Args::default())));
+        assert_eq!(
+            parse("run 12 --part 2 --input inputs/x.txt"),
+            Some(run(12, cli::Args { part: Some(2), input: Some(PathBuf::from("inputs/x.txt")), ..cli::Args::default() }))
+        );
+        assert_eq!(
+            parse("run 12 inputs/x.txt"),
+            Some(run(12, cli::Args { input_file: Some(PathBuf::from("inputs/x.txt")), ..cli::Args::default() }))
+        );
+    }
+
+    #[test]
+    fn example_takes_an_optional_number() {
+        assert_eq!(parse("run 1 --example"), Some(run(1, cli::Args { example: Some(1), ..cli::Args::default() })));
+        assert_eq!(parse("run 1 -e 2"), Some(run(1, cli::Args { example: Some(2), ..cli::Args::default() })));
+    }
+
+    #[test]
+    fn timeout_takes_seconds() {
+        let timeout = |args: &str| match parse(args) {
+            Some(Command::Run(run)) => run.timeout,
+            _ => None,
+        };
+        assert_eq!(timeout("run 1 --timeout 10"), Some(Duration::from_secs(10)));
+        assert_eq!(timeout("run --all --timeout 0.5"), Some(Duration::from_millis(500)));
+        assert_eq!(parse("run 1 --timeout soon"), None);
+        assert_eq!(parse("run 1 --timeout -1"), None);
+    }
+
+    #[test]
+    fn warmup_takes_a_count() {
+        assert_eq!(parse("run 1 --warmup 5"), Some(Command::Run(RunArgs { day: Some(1), warmup: Some(5), ..RunArgs::default() })));
+    }
+
+    #[test]
+    fn bench_runs_takes_a_count() {
+        assert_eq!(
+            parse("run 1 --bench-runs 20 --warmup 3"),
+            Some(Command::Run(RunArgs { day: Some(1), warmup: Some(3), bench_runs: Some(20), ..RunArgs::default() }))
+        );
+        assert_eq!(parse("run 1 --bench-runs 0"), None);
+        assert_eq!(parse("run 1 --bench-runs 5 --json"), None);
+        assert_eq!(parse("run --all --bench-runs 5"), None);
+    }
+
+    #[test]
+    fn no_color_is_global() {
+        let cli = Cli::try_parse_from(["aoc", "run", "1", "--no-color"]).unwrap();
+        assert!(cli.no_color);
+        assert!(Cli::try_parse_from(["aoc", "--no-color", "verify"]).unwrap().no_color);
+        assert!(!Cli::try_parse_from(["aoc", "verify"]).unwrap().no_color);
+    }
+
+    #[test]
+    fn quiet_is_a_switch() {
+        assert_eq!(parse("run 1 -q"), Some(run(1, cli::Args { quiet: true, ..cli::Args::default() })));
+        assert_eq!(parse("run 1 --quiet"), Some(run(1, cli::Args { quiet: true, ..cli::Args::default() })));
+    }
+
+    #[test]
+    fn json_is_a_switch() {
+        assert_eq!(
+            parse("run 2 --json --part 1"),
+            Some(Command::Run(RunArgs {
+                day: Some(2),
+                common: cli::Args { part: Some(1), ..cli::Args::default() },
+                json: true,
+                ..RunArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn day_defaults_to_today() {
+        assert_eq!(parse("run"), Some(Command::Run(RunArgs::default())));
+        assert_eq!(
+            parse("run --part 2"),
+            Some(Command::Run(RunArgs { common: cli::Args { part: Some(2), ..cli::Args::default() }, ..RunArgs::default() }))
+        );
+    }
+
+    #[test]
+    fn run_all_takes_no_day() {
+        assert_eq!(parse("run --all"), Some(Command::Run(RunArgs { all: true, ..RunArgs::default() })));
+        assert_eq!(parse("run --all --json"), Some(Command::Run(RunArgs { all: true, json: true, ..RunArgs::default() })));
+        assert_eq!(parse("run --all 3"), None);
+    }
+
+    #[test]
+    fn fetch_takes_a_day() {
+        assert_eq!(parse("fetch 3"), Some(Command::Fetch { day: 3 }));
+        assert_eq!(parse("login"), Some(Command::Login));
+        assert_eq!(parse("logout"), Some(Command::Logout));
+        assert_eq!(parse("fetch"), None);
+    }
+
+    #[test]
+    fn new_takes_a_day() {
+        assert_eq!(parse("new 2"), Some(Command::New { day: 2 }));
+        assert_eq!(parse("new"), None);
+    }
+
+    #[test]
+    fn desc_takes_a_day() {
+        assert_eq!(parse("desc 4"), Some(Command::Describe { day: 4 }));
+        assert_eq!(parse("open 4"), Some(Command::Describe { day: 4 }));
+    }
+
+    #[test]
+    fn verify_takes_no_arguments() {
+        assert_eq!(parse("verify"), Some(Command::Verify));
+    }
+
+    #[test]
+    fn watch_takes_a_day() {
+        assert_eq!(parse("watch 5"), Some(Command::Watch { day: 5 }));
+        assert_eq!(parse("watch"), None);
+    }
+
+    #[test]
+    fn submit_takes_day_and_part() {
+        assert_eq!(parse("submit 3 2"), Some(Command::Submit(SubmitArgs { day: 3, part: 2, input: None })));
+        assert_eq!(
+            parse("submit 3 1 --input x.txt"),
+            Some(Command::Submit(SubmitArgs { day: 3, part: 1, input: Some(PathBuf::from("x.txt")) }))
+        );
+        assert_eq!(parse("submit 3"), None);
+        assert_eq!(parse("submit 3 0"), None);
+    }
+
+    #[test]
+    fn days_are_limited_to_the_calendar() {
+        assert_eq!(parse("run 26"), None);
+        assert_eq!(parse("fetch 0"), None);
+        assert_eq!(parse("watch 12"), Some(Command::Watch { day: 12 }));
+    }
+
+    #[test]
+    fn completions_offer_days_and_parts() {
+        assert_eq!(parse("completions zsh"), Some(Command::Completions { shell: Shell::Zsh }));
+        assert_eq!(parse("completions tcsh"), None);
+
+        let mut script = Vec::new();
+        clap_complete::generate(Shell::Zsh, &mut Cli::command(), "aoc", &mut script);
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("--no-color"));
+        assert!(script.contains(":(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25)"));
+        assert!(script.contains(":PART:(1 2)"));
+    }
+
+    #[test]
+    fn countdown_can_wait() {
+        assert_eq!(parse("countdown"), Some(Command::Countdown { wait: false }));
+        assert_eq!(parse("countdown --wait"), Some(Command::Countdown { wait: true }));
+    }
+
+    #[test]
+    fn countdowns_drop_leading_zero_units() {
+        assert_eq!(format_countdown(Duration::from_secs(9)), "9s");
+        assert_eq!(format_countdown(Duration::from_secs(65)), "1m 05s");
+        assert_eq!(format_countdown(Duration::from_hours(1)), "1h 00m 00s");
+        assert_eq!(format_countdown(Duration::from_secs(2 * 86_400 + 3 * 3_600 + 4 * 60 + 5)), "2d 03h 04m 05s");
+    }
+
+    #[test]
+    fn leaderboard_takes_an_optional_id() {
+        assert_eq!(parse("leaderboard"), Some(Command::Leaderboard { id: None }));
+        assert_eq!(parse("leaderboard 123456"), Some(Command::Leaderboard { id: Some(123_456) }));
+        assert_eq!(parse("leaderboard mine"), None);
+    }
+
+    #[test]
+    fn year_is_global() {
+        assert_eq!(Cli::try_parse_from(["aoc", "--year", "2024", "fetch", "25"]).unwrap().year, Some(2024));
+        assert_eq!(Cli::try_parse_from(["aoc", "run", "3", "--year", "2016"]).unwrap().year, Some(2016));
+        assert!(Cli::try_parse_from(["aoc", "status", "--year", "2014"]).is_err());
+        assert_eq!(parse("watch 25"), Some(Command::Watch { day: 25 }));
+    }
+
+    #[test]
+    fn config_defaults_can_be_turned_off() {
+        let config = DayConfig { json: Some(true), quiet: Some(true), ..DayConfig::default() };
+        let merged = |args: &str| match parse(args) {
+            Some(Command::Run(args)) => {
+                let args = apply_config(args, config.clone());
+                (args.json, args.common.quiet)
+            }
+            _ => panic!("{args} did not parse"),
+        };
+        assert_eq!(merged("run 1"), (true, true));
+        assert_eq!(merged("run 1 --no-json --no-quiet"), (false, false));
+        assert_eq!(merged("run 1 --no-json --json"), (true, true));
+        assert_eq!(merged("run 1 -q --no-quiet"), (true, false));
+        assert_eq!(parse("run 1 --bench-runs 3 --json"), None);
+        assert!(plain_bench(&apply_config(RunArgs { bench_runs: Some(3), ..RunArgs::default() }, config.clone())).is_err());
+        assert!(plain_bench(&apply_config(RunArgs { bench_runs: Some(3), no_json: true, ..RunArgs::default() }, config)).is_ok());
+    }
+
+    #[test]
+    fn input_dir_replaces_input() {
+        let args = RunArgs { day: Some(3), input_dir: Some(PathBuf::from("bench_inputs")), ..RunArgs::default() };
+        assert_eq!(parse("run 3 --input-dir bench_inputs"), Some(Command::Run(args)));
+        assert_eq!(parse("run 3 --input-dir bench_inputs --input x.txt"), None);
+        assert_eq!(parse("run --all --input-dir bench_inputs"), None);
+    }
+
+    #[test]
+    fn compare_runs_ten_times_by_default() {
+        let compare = |runs| Some(Command::Compare(CompareArgs { day: 1, input: None, part: None, runs }));
+        assert_eq!(parse("compare 1"), compare(10));
+        assert_eq!(parse("compare 1 --runs 3"), compare(3));
+        assert_eq!(parse("compare 1 --runs 0"), None);
+    }
+
+    #[test]
+    fn report_defaults_to_markdown() {
+        assert_eq!(parse("report"), Some(Command::Report { html: false, output: None, timeout: None }));
+        assert_eq!(
+            parse("report --html -o out.html"),
+            Some(Command::Report { html: true, output: Some(PathBuf::from("out.html")), timeout: None })
+        );
+    }
+
+    #[test]
+    fn dashboard_is_also_tui() {
+        assert_eq!(parse("tui"), Some(Command::Dashboard { timeout: None }));
+        assert_eq!(parse("dashboard --timeout 2"), Some(Command::Dashboard { timeout: Some(Duration::from_secs(2)) }));
+    }
+
+    #[test]
+    fn status_takes_no_arguments() {
+        assert_eq!(parse("status"), Some(Command::Status));
+        assert_eq!(parse("status 1"), None);
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("walk 1"), None);
+        assert_eq!(parse("run one"), None);
+        assert_eq!(parse("run 1 --part 3"), None);
+        assert_eq!(parse("run 1 --part"), None);
+        assert_eq!(parse("run 1 --bogus x"), None);
+        assert_eq!(parse("run 1 --warmup many"), None);
+    }
+
+    #[test]
+    fn failures_have_distinct_exit_codes() {
+        let exit = |panicked, timed_out| outcome(1, &RunResult { panicked, timed_out, ..RunResult::default() }, None).err().map(|failure| failure.exit);
+        assert_eq!(exit(None, None), None);
+        assert_eq!(exit(Some(Phase::Parse), None), Some(Exit::ParseFailed));
+        assert_eq!(exit(Some(Phase::Part(2)), None), Some(Exit::Panicked));
+        assert_eq!(exit(None, Some(Phase::Part(1))), Some(Exit::TimedOut));
+        assert_eq!(Exit::WrongAnswer as u8, 5);
+    }
+
+    #[test]
+    fn other_inputs_are_not_checked() {
+        let with_input = |input: Option<PathBuf>| RunArgs { common: cli::Args { input, ..cli::Args::default() }, ..RunArgs::default() };
+        assert!(own_input(1, &with_input(None)));
+        assert!(own_input(1, &with_input(Some(default_input(1)))));
+        assert!(!own_input(1, &with_input(Some(PathBuf::from("friend.txt")))));
+        let friend = with_input(Some(PathBuf::from("friend.txt")));
+        assert_eq!(expected(&Answers::default(), 1, &friend, 1, &Answer::from(3_u8)), Check::Unknown);
+    }
+
+}
@@ -0,0 +1,210 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+const TEMPLATE: &str = r#"use crate::solution::{Answer, Solution};
+
+pub struct Day{N};
+
+crate::aoc_day!({N}, Day{N});
+
+/// Answers both parts for a puzzle input.
+#[must_use]
+pub fn solve(input: &str) -> (Answer, Answer) {
+    Day{N}::solve(input)
+}
+
+impl Solution for Day{N} {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        input.lines().map(str::to_owned).collect()
+    }
+
+    fn part1(_parsed: &Self::Parsed) -> Answer {
+        0_u64.into()
+    }
+
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        0_u64.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::{days::day{N}::Day{N}, solution::Solution};
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "example not filled in yet"]
+    fn example_answers() {
+        let parsed = Day{N}::parse(EXAMPLE);
+        assert_eq!(Day{N}::part1(&parsed), "0");
+        assert_eq!(Day{N}::part2(&parsed), "0");
+    }
+
+}
+"#;
+
+/// The source of a new, registered but unsolved day.
+pub fn day_source(day: u8) -> String {
+    TEMPLATE.replace("{N}", &day.to_string())
+}
+
+/// Where a new day's line goes among `entries`, `(line index, day)` pairs in day order: before
+/// the first later day, else after the last one. `None` when there are no entries yet.
+fn insertion_point(entries: &[(usize, u8)], day: u8) -> Option<usize> {
+    entries.iter().find(|&&(_, n)| n > day).map(|&(i, _)| i).or_else(|| entries.last().map(|&(i, _)| i + 1))
+}
+
+/// Adds `N => dayN, "dayN";` to the `day_modules!` list in `days/mod.rs`, keeping it in order.
+pub fn register_module(source: &str, day: u8) -> String {
+    let line = format!("    {day} => day{day}, \"day{day}\";");
+    let modules: Vec<(usize, u8)> = source
+        .lines()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, l.trim_start().split_once(" => day")?.0.parse().ok()?)))
+        .collect();
+    let insert_at = insertion_point(&modules, day)
+        .or_else(|| source.lines().position(|l| l == "day_modules! {").map(|i| i + 1))
+        .unwrap_or(0);
+
+    let mut lines: Vec<&str> = source.lines().collect();
+    lines.insert(insert_at, &line);
+    lines.join("\n") + "\n"
+}
+
+/// Adds `N, "dayN" => aoc2025::days::dayN::DayN,` to the `bench_days!` list in `benches/days.rs`.
+pub fn register_bench(source: &str, day: u8) -> Option<String> {
+    let start = source.find("bench_days! {")?;
+    let end = start + source[start..].find("\n}")?;
+    Some(format!("{}\n    {day}, \"day{day}\" => aoc2025::days::day{day}::Day{day},{}", &source[..end], &source[end..]))
+}
+
+/// Adds a `dayN = []` feature to `Cargo.toml` and lists it in `full`, keeping both in day order.
+pub fn register_feature(manifest: &str, day: u8) -> Option<String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_owned).collect();
+    let full = lines.iter().position(|l| l.starts_with("full = ["))?;
+    let listed = lines[full].strip_prefix("full = [")?.strip_suffix(']')?;
+    let feature = format!("\"day{day}\"");
+    let mut features: Vec<&str> = listed.split(',').map(str::trim).filter(|f| !f.is_empty()).collect();
+    features.push(&feature);
+    features.sort_by_key(|f| f.trim_matches('"').strip_prefix("day").and_then(|n| n.parse::<u8>().ok()));
+    lines[full] = format!("full = [{}]", features.join(", "));
+
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, l.strip_prefix("day")?.strip_suffix(" = []")?.parse().ok()?)))
+        .collect();
+    lines.insert(insertion_point(&days, day).unwrap_or(full + 1), format!("day{day} = []"));
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates the day's module, an empty input and registers the day, returning the files touched.
+pub fn scaffold(root: &Path, input: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let days = root.join("src").join("days");
+    let module = days.join(format!("day{day}.rs"));
+    if module.exists() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", module.display())));
+    }
+
+    fs::write(&module, day_source(day))?;
+    let mod_rs = days.join("mod.rs");
+    fs::write(&mod_rs, register_module(&fs::read_to_string(&mod_rs)?, day))?;
+    let mut touched = vec![module, mod_rs];
+
+    let manifest = root.join("Cargo.toml");
+    if let Some(source) = fs::read_to_string(&manifest).ok().and_then(|source| register_feature(&source, day)) {
+        fs::write(&manifest, source)?;
+        touched.push(manifest);
+    }
+
+    let bench = root.join("benches").join("days.rs");
+    if let Some(source) = fs::read_to_string(&bench).ok().and_then(|source| register_bench(&source, day)) {
+        fs::write(&bench, source)?;
+        touched.push(bench);
+    }
+
+    if !input.exists() {
+        if let Some(dir) = input.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(input, "")?;
+        touched.push(input.to_owned());
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::scaffold::{day_source, register_bench, register_feature, register_module, scaffold};
+
+    #[test]
+    fn template_is_filled_in() {
+        let source = day_source(7);
+        assert!(source.contains("pub struct Day7;"));
+        assert!(source.contains("crate::aoc_day!(7, Day7);"));
+        assert!(source.contains("pub fn solve(input: &str) -> (Answer, Answer) {\n    Day7::solve(input)"));
+        assert!(source.contains("use crate::{days::day7::Day7, solution::Solution};"));
+        assert!(!source.contains("{N}"));
+    }
+
+    #[test]
+    fn modules_stay_in_order() {
+        let source = "day_modules! {\n    1 => day1, \"day1\";\n    10 => day10, \"day10\";\n}\n";
+        assert_eq!(
+            register_module(source, 3),
+            "day_modules! {\n    1 => day1, \"day1\";\n    3 => day3, \"day3\";\n    10 => day10, \"day10\";\n}\n"
+        );
+        assert_eq!(
+            register_module(source, 12),
+            "day_modules! {\n    1 => day1, \"day1\";\n    10 => day10, \"day10\";\n    12 => day12, \"day12\";\n}\n"
+        );
+        assert_eq!(register_module("day_modules! {\n}\n", 4), "day_modules! {\n    4 => day4, \"day4\";\n}\n");
+    }
+
+    #[test]
+    fn benches_are_registered() {
+        let source = "bench_days! {\n    1, \"day1\" => aoc2025::days::day1::Day1,\n}\n\ncriterion_group!(benches, days);\n";
+        assert_eq!(
+            register_bench(source, 2).unwrap(),
+            "bench_days! {\n    1, \"day1\" => aoc2025::days::day1::Day1,\n    2, \"day2\" => aoc2025::days::day2::Day2,\n}\n\ncriterion_group!(benches, days);\n"
+        );
+        assert_eq!(register_bench("fn main() {}\n", 2), None);
+    }
+
+    #[test]
+    fn features_are_registered_in_order() {
+        let manifest = "[features]\ndefault = [\"full\"]\nfull = [\"day1\", \"day10\"]\nday1 = []\nday10 = []\nmmap = []\n";
+        assert_eq!(
+            register_feature(manifest, 3).unwrap(),
+            "[features]\ndefault = [\"full\"]\nfull = [\"day1\", \"day3\", \"day10\"]\nday1 = []\nday3 = []\nday10 = []\nmmap = []\n"
+        );
+        assert_eq!(register_feature("full = []\n", 1).unwrap(), "full = [\"day1\"]\nday1 = []\n");
+        assert_eq!(register_feature("[package]\n", 1), None);
+    }
+
+    #[test]
+    fn scaffold_creates_files_once() {
+        let root = std::env::temp_dir().join(format!("aoc2025-{}-scaffold", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(root.join("src/days/mod.rs"), "day_modules! {\n    1 => day1, \"day1\";\n}\n").unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[features]\nfull = [\"day1\"]\nday1 = []\n").unwrap();
+        let input = root.join("inputs/day2.txt");
+
+        let touched = scaffold(&root, &input, 2).unwrap();
+        assert_eq!(touched.len(), 4);
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+            "day_modules! {\n    1 => day1, \"day1\";\n    2 => day2, \"day2\";\n}\n"
+        );
+        assert_eq!(std::fs::read_to_string(root.join("Cargo.toml")).unwrap(), "[features]\nfull = [\"day1\", \"day2\"]\nday1 = []\nday2 = []\n");
+        assert_eq!(std::fs::read_to_string(&input).unwrap(), "");
+        assert!(scaffold(&root, &input, 2).is_err());
+    }
+
+}
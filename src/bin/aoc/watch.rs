@@ -0,0 +1,86 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often watched files are polled for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The modification time of every file under `paths`, sorted by path. Missing paths are skipped.
+pub fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, SystemTime)> {
+    let mut files = Vec::new();
+    for path in paths {
+        collect(path, &mut files);
+    }
+    files.sort();
+    files
+}
+
+fn collect(path: &Path, files: &mut Vec<(PathBuf, SystemTime)>) {
+    let Ok(metadata) = fs::metadata(path) else { return };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), files);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        files.push((path.to_owned(), modified));
+    }
+}
+
+/// Re-runs `aoc run <day>` through cargo, so source changes are rebuilt, whenever `paths` change.
+pub fn watch(day: u8, paths: &[PathBuf]) -> io::Result<()> {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let mut last = None;
+    loop {
+        let current = snapshot(paths);
+        if last.as_ref() != Some(&current) {
+            println!("\n--- day {day}, {} watched files ---", current.len());
+            let status = Command::new(env!("CARGO"))
+                .args(["run", "--quiet", "--bin", "aoc", "--manifest-path"])
+                .arg(&manifest)
+                .args(["--", "run", &day.to_string()])
+                .args((!aoc2025::style::enabled()).then_some("--no-color"))
+                .env("AOC_YEAR", aoc2025::calendar::year().to_string())
+                .status()?;
+            if !status.success() {
+                println!("--- failed ({status}), waiting for changes ---");
+            }
+            last = Some(current);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::{fs::File, time::{Duration, SystemTime}};
+
+    use crate::watch::snapshot;
+
+    #[test]
+    fn snapshot_sees_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-watch", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("a.rs"), "a").unwrap();
+        std::fs::write(dir.join("nested/b.rs"), "b").unwrap();
+        let missing = dir.with_extension("txt");
+        let _ = std::fs::remove_file(&missing);
+
+        let before = snapshot(&[dir.clone(), missing.clone()]);
+        assert_eq!(before.len(), 2);
+        assert_eq!(snapshot(&[dir.clone(), missing.clone()]), before);
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        File::options().write(true).open(dir.join("nested/b.rs")).unwrap().set_modified(later).unwrap();
+        assert_ne!(snapshot(&[dir.clone(), missing.clone()]), before);
+
+        std::fs::write(&missing, "new").unwrap();
+        assert_eq!(snapshot(&[dir, missing]).len(), 3);
+    }
+
+}
@@ -0,0 +1,111 @@
+use std::{fs, hash::Hasher, path::{Path, PathBuf}};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use aoc_utils::InputError;
+
+/// The directory parsed inputs are cached in: `.cache/` in the crate root.
+#[must_use]
+pub fn cache_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(".cache")
+}
+
+/// Parses `filename` with `parse`, or returns the result cached by a previous run on identical input.
+///
+/// `name` distinguishes caches for different days or parser versions; bump it when the parsed
+/// structure changes. A missing or unreadable cache entry just falls back to parsing, and one
+/// that can't be written is only warned about.
+#[allow(clippy::missing_errors_doc)]
+pub fn cached<T, P, F>(filename: P, name: &str, parse: F) -> Result<T, InputError>
+where T: Serialize + DeserializeOwned, P: AsRef<Path>, F: FnOnce(&str) -> T, {
+    cached_in(&cache_dir(), filename, name, parse)
+}
+
+fn cached_in<T, P, F>(dir: &Path, filename: P, name: &str, parse: F) -> Result<T, InputError>
+where T: Serialize + DeserializeOwned, P: AsRef<Path>, F: FnOnce(&str) -> T, {
+    let text = aoc_utils::read_to_string(filename)?;
+    let entry = dir.join(format!("{name}-{:016x}.json", fnv1a(text.as_bytes())));
+
+    if let Some(value) = fs::read(&entry).ok().and_then(|bytes| serde_json::from_slice(&bytes).ok()) {
+        return Ok(value);
+    }
+
+    let value = parse(&text);
+    let saved = fs::create_dir_all(dir).and_then(|()| fs::write(&entry, serde_json::to_vec(&value)?));
+    if let Err(e) = saved {
+        tracing::warn!("failed to cache {}: {e}", entry.display());
+    }
+    Ok(value)
+}
+
+/// FNV-1a, chosen over `DefaultHasher` because its output is stable across Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::cell::Cell;
+
+    use crate::test::write_temp;
+
+    #[test]
+    fn second_run_hits_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-cache", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let input = write_temp("cache-input.txt", "1\n2\n3\n");
+        let parses = Cell::new(0);
+        let parse = |text: &str| {
+            parses.set(parses.get() + 1);
+            text.lines().map(|l| l.parse::<u32>().unwrap()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(super::cached_in(&dir, &input, "numbers", parse).unwrap(), vec![1, 2, 3]);
+        assert_eq!(super::cached_in(&dir, &input, "numbers", parse).unwrap(), vec![1, 2, 3]);
+        assert_eq!(parses.get(), 1);
+
+        std::fs::write(&input, "4\n").unwrap();
+        assert_eq!(super::cached_in(&dir, &input, "numbers", parse).unwrap(), vec![4]);
+        assert_eq!(parses.get(), 2);
+    }
+
+    #[test]
+    fn unwritable_cache_still_parses() {
+        // A directory can't be created inside a regular file.
+        let dir = write_temp("cache-blocker.txt", "").join("cache");
+        let input = write_temp("cache-unwritable.txt", "7\n");
+        let parse = |text: &str| text.trim().parse::<u32>().unwrap();
+        assert_eq!(super::cached_in(&dir, &input, "number", parse).unwrap(), 7);
+        assert!(super::cached_in(&dir, "no-such-input.txt", "number", parse).is_err());
+    }
+
+    #[test]
+    fn fnv1a_matches_reference() {
+        assert_eq!(super::fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(super::fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+}
@@ -0,0 +1,149 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicI32, Ordering},
+    time::{Duration, SystemTime},
+};
+
+/// The event this crate's own solutions are for.
+pub const YEAR: i32 = 2025;
+
+/// The number of puzzles in this year's event.
+pub const LAST_DAY: u8 = days_in(YEAR);
+
+/// The most puzzles any event has had.
+pub const MAX_DAY: u8 = 25;
+
+static YEAR_OVERRIDE: AtomicI32 = AtomicI32::new(0);
+
+/// Selects the event for the rest of the run, e.g. from `--year`.
+pub fn set_year(year: i32) {
+    YEAR_OVERRIDE.store(year, Ordering::Relaxed);
+}
+
+/// The event being solved: [`set_year`], `$AOC_YEAR`, `year` from `.aoc.toml`, or [`YEAR`].
+#[must_use]
+pub fn year() -> i32 {
+    let chosen = Some(YEAR_OVERRIDE.load(Ordering::Relaxed)).filter(|&year| year != 0);
+    let from_env = env::var("AOC_YEAR").ok().and_then(|year| year.trim().parse().ok());
+    chosen.or(from_env).or_else(|| crate::config::get().year).unwrap_or(YEAR)
+}
+
+/// The number of puzzles in `year`'s event: 25 until 2024, 12 since.
+#[must_use]
+pub const fn days_in(year: i32) -> u8 {
+    if year < 2025 { 25 } else { 12 }
+}
+
+/// `base/<year>` for the event being solved, or just `base` for [`YEAR`] unless `base/<year>`
+/// exists, so this year's files can stay where they always were.
+#[must_use]
+pub fn year_dir(base: &Path) -> PathBuf {
+    year_dir_for(base, year())
+}
+
+fn year_dir_for(base: &Path, year: i32) -> PathBuf {
+    let dir = base.join(year.to_string());
+    if year != YEAR || dir.is_dir() { dir } else { base.to_path_buf() }
+}
+
+/// When `day`'s puzzle unlocks: midnight US/Eastern (05:00 UTC) on that day of December.
+#[must_use]
+pub fn unlock_time(day: u8) -> SystemTime {
+    let days = days_from_civil(year(), 12, u32::from(day));
+    let seconds = u64::try_from(days).unwrap_or(0) * 86_400 + 5 * 3_600;
+    SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+#[must_use]
+pub fn is_unlocked(day: u8, now: SystemTime) -> bool {
+    now >= unlock_time(day)
+}
+
+/// The next puzzle to unlock after `now`, or `None` once the last one has.
+#[must_use]
+pub fn next_unlock(now: SystemTime) -> Option<u8> {
+    (1..=days_in(year())).find(|&day| !is_unlocked(day, now))
+}
+
+/// The puzzle of the day at `now`, US/Eastern: the last one unlocked, until the day after the
+/// final puzzle unlocks. `None` outside the event.
+#[must_use]
+pub fn current_day(now: SystemTime) -> Option<u8> {
+    let last_day = days_in(year());
+    if is_unlocked(last_day + 1, now) {
+        return None;
+    }
+    (1..=last_day).rev().find(|&day| is_unlocked(day, now))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+const fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year } as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, SystemTime};
+
+    use crate::calendar::{
+        LAST_DAY, YEAR, current_day, days_from_civil, days_in, is_unlocked, next_unlock, unlock_time, year_dir_for,
+    };
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2025, 12, 1), 20_423);
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        // 2025-12-01T05:00:00Z
+        let unlock = SystemTime::UNIX_EPOCH + Duration::from_hours(490_157);
+        assert_eq!(unlock_time(1), unlock);
+        assert!(!is_unlocked(1, unlock - Duration::from_secs(1)));
+        assert!(is_unlocked(1, unlock));
+        assert_eq!(unlock_time(2), unlock + Duration::from_hours(24));
+    }
+
+    #[test]
+    fn current_day_follows_unlocks() {
+        let second = Duration::from_secs(1);
+        assert_eq!(current_day(unlock_time(1) - second), None);
+        assert_eq!(current_day(unlock_time(1)), Some(1));
+        assert_eq!(current_day(unlock_time(5) - second), Some(4));
+        assert_eq!(current_day(unlock_time(LAST_DAY + 1) - second), Some(LAST_DAY));
+        assert_eq!(current_day(unlock_time(LAST_DAY + 1)), None);
+    }
+
+    #[test]
+    fn events_have_their_own_lengths_and_directories() {
+        assert_eq!(days_in(2015), 25);
+        assert_eq!(days_in(2024), 25);
+        assert_eq!(days_in(YEAR), LAST_DAY);
+
+        let base = std::env::temp_dir().join(format!("aoc2025-{}-years", std::process::id()));
+        assert_eq!(year_dir_for(&base, 2024), base.join("2024"));
+        assert_eq!(year_dir_for(&base, YEAR), base);
+        std::fs::create_dir_all(base.join(YEAR.to_string())).unwrap();
+        assert_eq!(year_dir_for(&base, YEAR), base.join(YEAR.to_string()));
+        std::fs::remove_dir_all(base).unwrap();
+    }
+
+    #[test]
+    fn next_unlock_is_the_first_locked_day() {
+        assert_eq!(next_unlock(SystemTime::UNIX_EPOCH), Some(1));
+        assert_eq!(next_unlock(unlock_time(3) - Duration::from_secs(1)), Some(3));
+        assert_eq!(next_unlock(unlock_time(3)), Some(4));
+        assert_eq!(next_unlock(unlock_time(LAST_DAY)), None);
+    }
+
+}
@@ -0,0 +1,130 @@
+use std::{ops::RangeInclusive, path::PathBuf};
+
+use clap::{
+    Parser,
+    builder::{PossibleValue, PossibleValuesParser, TypedValueParser},
+};
+
+use crate::calendar;
+
+/// Options shared by every binary: which input to read, which part to solve and what to print.
+///
+/// Per-day binaries parse it directly with [`Parser::parse`]; the `aoc` runner flattens it into
+/// its subcommands.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Parser)]
+pub struct Args {
+    /// Input file, or `-` for stdin [default: inputs/dayN.txt]
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+    /// Input file, as with `--input`
+    #[arg(value_name = "INPUT", conflicts_with_all = ["input", "example"])]
+    pub input_file: Option<PathBuf>,
+    /// Solve only this part
+    #[arg(short, long, value_parser = part_parser())]
+    pub part: Option<u8>,
+    /// Use example input N, inputs/dayN.example[N].txt
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1",
+          value_parser = clap::value_parser!(u8).range(1..), conflicts_with = "input")]
+    pub example: Option<u8>,
+    /// Print only the answers
+    #[arg(short, long)]
+    pub quiet: bool,
+    /// Print how long each phase took
+    #[arg(short, long)]
+    pub time: bool,
+    /// Log more to stderr: -v for progress, -vv for every step (overridden by `RUST_LOG`)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+impl Args {
+    /// The input given on the command line, with `--input` or as the positional `INPUT`.
+    #[must_use]
+    pub fn input(&self) -> Option<&PathBuf> {
+        self.input.as_ref().or(self.input_file.as_ref())
+    }
+
+    /// The input to read for `day`: `--input`, the example with `--example`, or the day's default.
+    #[must_use]
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        match (self.input(), self.example) {
+            (Some(path), _) => path.clone(),
+            (None, Some(n)) => crate::example_input(day, n),
+            (None, None) => crate::default_input(day),
+        }
+    }
+
+    /// The parts to solve: the one given with `--part`, or both.
+    #[must_use]
+    pub fn parts(&self) -> Vec<u8> {
+        self.part.map_or_else(|| vec![1, 2], |part| vec![part])
+    }
+}
+
+/// Parses a day number from 1 to [`calendar::MAX_DAY`], offering each to shell completion.
+#[must_use]
+pub fn day_parser() -> impl TypedValueParser<Value = u8> {
+    numbers(1..=calendar::MAX_DAY)
+}
+
+/// Parses a part number, 1 or 2, offering both to shell completion.
+#[must_use]
+pub fn part_parser() -> impl TypedValueParser<Value = u8> {
+    numbers(1..=2)
+}
+
+fn numbers(range: RangeInclusive<u8>) -> impl TypedValueParser<Value = u8> {
+    PossibleValuesParser::new(range.map(|n| PossibleValue::new(n.to_string()))).try_map(|n| n.parse::<u8>())
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    use clap::{CommandFactory, Parser};
+
+    use crate::{cli::Args, default_input, example_input};
+
+    fn parse(args: &str) -> Result<Args, clap::Error> {
+        Args::try_parse_from(std::iter::once("bin").chain(args.split_whitespace()))
+    }
+
+    #[test]
+    fn definition_is_valid() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn flags_are_parsed() {
+        assert_eq!(parse("").unwrap(), Args::default());
+        assert_eq!(
+            parse("-i x.txt -p 2 -q -t").unwrap(),
+            Args { input: Some(PathBuf::from("x.txt")), input_file: None, part: Some(2), example: None, quiet: true, time: true, verbose: 0 }
+        );
+        assert_eq!(parse("- -q").unwrap().input(), Some(&PathBuf::from("-")));
+        assert!(parse("x.txt --input y.txt").is_err());
+        assert!(parse("x.txt --example").is_err());
+        assert_eq!(parse("-vv").unwrap().verbose, 2);
+        assert!(parse("--part 3").is_err());
+        assert_eq!(parse("--example").unwrap().example, Some(1));
+        assert_eq!(parse("-e 2").unwrap().example, Some(2));
+        assert!(parse("--example 0").is_err());
+        assert!(parse("--example --input x.txt").is_err());
+        assert!(parse("--bogus").is_err());
+    }
+
+    #[test]
+    fn input_and_parts_default() {
+        assert_eq!(parse("").unwrap().input_path(4), default_input(4));
+        assert_eq!(parse("--example").unwrap().input_path(4), example_input(4, 1));
+        assert_eq!(parse("--example 3").unwrap().input_path(4), example_input(4, 3));
+        assert!(example_input(4, 1).ends_with("day4.example.txt"));
+        assert!(example_input(4, 3).ends_with("day4.example3.txt"));
+        assert_eq!(parse("--input -").unwrap().input_path(4), PathBuf::from("-"));
+        assert_eq!(parse("-").unwrap().input_path(4), PathBuf::from("-"));
+        assert_eq!(parse("").unwrap().parts(), vec![1, 2]);
+        assert_eq!(parse("--part 2").unwrap().parts(), vec![2]);
+    }
+
+}
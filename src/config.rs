@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, fs, io, path::{Path, PathBuf}, sync::OnceLock};
+
+use serde::Deserialize;
+
+/// Settings from `.aoc.toml` in the crate root. Every field is optional:
+///
+/// ```toml
+/// year = 2025
+/// session_file = "~/.config/aoc/session"
+/// leaderboard = 123456
+/// contact = "you@example.com"
+/// input_dir = "inputs"
+/// json = false
+/// quiet = false
+/// warmup = 0
+///
+/// [days.3]
+/// input = "inputs/day3-stress.txt"
+/// warmup = 10
+/// ```
+///
+/// Command-line flags override these, and these override the built-in defaults.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<i32>,
+    /// A file holding the session cookie, used when `AOC_SESSION` isn't set.
+    pub session_file: Option<PathBuf>,
+    /// The private leaderboard `aoc leaderboard` shows when not given an id.
+    pub leaderboard: Option<u64>,
+    /// An email address or handle added to the User-Agent sent to adventofcode.com, so its
+    /// maintainers can get in touch about the traffic.
+    pub contact: Option<String>,
+    pub input_dir: Option<PathBuf>,
+    pub quiet: Option<bool>,
+    pub json: Option<bool>,
+    pub warmup: Option<u32>,
+    pub days: BTreeMap<String, DayConfig>,
+}
+
+/// Run settings for one day, overriding the top-level defaults.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayConfig {
+    pub input: Option<PathBuf>,
+    pub quiet: Option<bool>,
+    pub json: Option<bool>,
+    pub warmup: Option<u32>,
+}
+
+impl Config {
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        root().join(".aoc.toml")
+    }
+
+    /// Reads a config file, treating a missing file as empty.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// `day`'s settings with the top-level defaults filled in.
+    #[must_use]
+    pub fn day(&self, day: u8) -> DayConfig {
+        let own = self.days.get(&day.to_string()).cloned().unwrap_or_default();
+        DayConfig {
+            input: own.input.map(|path| resolve(&path)),
+            quiet: own.quiet.or(self.quiet),
+            json: own.json.or(self.json),
+            warmup: own.warmup.or(self.warmup),
+        }
+    }
+}
+
+/// The crate root, which relative paths in the config are resolved against.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Resolves a configured path: `~/` is the home directory, relative paths start at the crate root.
+#[must_use]
+pub fn resolve(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), std::env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => root().join(path),
+    }
+}
+
+/// The config loaded from `.aoc.toml`, read once. An unreadable file counts as empty here; the
+/// runner reports it by calling [`Config::load`] itself.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| Config::load(&Config::default_path()).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::{Path, PathBuf};
+
+    use crate::{config::{Config, DayConfig, resolve}, test::write_temp};
+
+    #[test]
+    fn missing_file_is_empty() {
+        assert_eq!(Config::load(Path::new("/nonexistent/.aoc.toml")).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn days_override_defaults() {
+        let path = write_temp(
+            "config.toml",
+            "year = 2024\nwarmup = 3\njson = true\n\n[days.2]\ninput = \"stress.txt\"\nwarmup = 10\n",
+        );
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.year, Some(2024));
+
+        assert_eq!(config.day(1), DayConfig { input: None, quiet: None, json: Some(true), warmup: Some(3) });
+        assert_eq!(
+            config.day(2),
+            DayConfig { input: Some(resolve(Path::new("stress.txt"))), quiet: None, json: Some(true), warmup: Some(10) }
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(Config::load(&write_temp("config-bad.toml", "yaer = 2024\n")).is_err());
+        assert!(Config::load(&write_temp("config-bad-day.toml", "[days.1]\npart = 2\n")).is_err());
+    }
+
+    #[test]
+    fn paths_resolve_from_the_crate_root() {
+        assert_eq!(resolve(Path::new("inputs")), Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        assert_eq!(resolve(Path::new("/abs/inputs")), PathBuf::from("/abs/inputs"));
+    }
+
+}
@@ -0,0 +1,52 @@
+use keyring::{Entry, Error};
+
+/// The keyring entry `aoc login` keeps the cookie under, named after the site rather than this
+/// crate so later years' runners find it too.
+const SERVICE: &str = "adventofcode.com";
+const USER: &str = "session";
+
+/// The session cookie saved by `aoc login`, if there is one and the keyring can be reached.
+#[must_use]
+pub fn load() -> Option<String> {
+    Entry::new(SERVICE, USER).and_then(|entry| entry.get_password()).ok().filter(|cookie| !cookie.is_empty())
+}
+
+/// Saves `cookie` in the OS keyring, replacing any earlier one.
+#[allow(clippy::missing_errors_doc)]
+pub fn store(cookie: &str) -> Result<(), Error> {
+    Entry::new(SERVICE, USER)?.set_password(cookie)
+}
+
+/// Removes the saved cookie, returning whether there was one.
+#[allow(clippy::missing_errors_doc)]
+pub fn delete() -> Result<bool, Error> {
+    match Entry::new(SERVICE, USER)?.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(Error::NoEntry) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// The cookie's value from a pasted line, which may be the whole `session=...` pair copied from
+/// the browser's developer tools.
+#[must_use]
+pub fn cookie_value(pasted: &str) -> Option<&str> {
+    let pasted = pasted.trim();
+    let value = pasted.strip_prefix("session=").unwrap_or(pasted).trim_end_matches(';');
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod test {
+
+    use crate::credentials::cookie_value;
+
+    #[test]
+    fn pasted_cookies_are_trimmed() {
+        assert_eq!(cookie_value("53616c7465645f5f\n"), Some("53616c7465645f5f"));
+        assert_eq!(cookie_value("  session=53616c7465645f5f;  "), Some("53616c7465645f5f"));
+        assert_eq!(cookie_value("session="), None);
+        assert_eq!(cookie_value("\n"), None);
+    }
+
+}
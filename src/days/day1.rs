@@ -0,0 +1,298 @@
+use std::num::ParseIntError;
+
+use tracing::debug;
+
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(i8)]
+pub enum Direction {
+    Left = -1,
+    Right = 1,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Dial {
+    position: u8,
+}
+
+impl Dial {
+    #[must_use]
+    pub const fn position(&self) -> u8 {
+        self.position
+    }
+
+    /// Turns the dial, returning how many times it passed or landed on zero.
+    ///
+    /// # Panics
+    /// Never in practice; the position is always wrapped back into `0..=99`.
+    pub fn turn(&mut self, rotation: &Rotation) -> u32 {
+
+        let steps: i32 = rotation.steps.cast_signed() * rotation.direction as i32;
+
+        let mut zero_crossings = (steps / 100).unsigned_abs();
+        let rem_steps = steps % 100;
+
+        let mut new_position = i32::from(self.position) + rem_steps;
+
+        // Correct out-of-bounds caused by <100 step rotation
+        if new_position < 0 {
+            new_position += 100;
+
+            // Going negative means we crossed zero - unless we were already at zero.
+            if self.position != 0 { zero_crossings += 1; }
+        }
+        if new_position > 99 {
+            new_position -= 100;
+
+            // If we landed on zero exactly, this crossing
+            // will be captured by the new_position == 0 check
+            if new_position != 0 { zero_crossings += 1; }
+        }
+
+        // Count landing on zero as a zero-crossing,
+        // unless it was achieved by an exact rotation,
+        // in which case this is captured already.
+        if new_position == 0 && rem_steps != 0 { zero_crossings += 1; }
+
+        self.position =
+            u8::try_from(new_position).expect("New position should alwayas be in the range 0..99");
+
+        zero_crossings
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RotationParseError {
+    IncorrectStartOfLineCharacter,
+    #[allow(dead_code)]
+    ParseIntError(ParseIntError),
+}
+
+impl TryFrom<&str> for Rotation {
+    type Error = RotationParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let direction = match value.chars().next() {
+            Some('L') => Ok(Direction::Left),
+            Some('R') => Ok(Direction::Right),
+            _ => Err(Self::Error::IncorrectStartOfLineCharacter),
+        }?;
+        let steps = value[1..].parse().map_err(Self::Error::ParseIntError)?;
+        Ok(Self { direction, steps })
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self { position: 50 }
+    }
+}
+
+pub struct Day1;
+
+crate::aoc_day!(1, Day1);
+
+/// Answers both parts for a puzzle input of one rotation per line.
+///
+/// # Panics
+/// If a line isn't a rotation such as `L68`.
+#[must_use]
+pub fn solve(input: &str) -> (Answer, Answer) {
+    Day1::solve(input)
+}
+
+impl Solution for Day1 {
+    type Parsed = Vec<Rotation>;
+
+    /// Parses one rotation per line, panicking with the 1-based line number on bad input.
+    fn parse(input: &str) -> Self::Parsed {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Rotation::try_from(line).unwrap_or_else(|e| panic!("Failed to parse line {}: {e:?}", i + 1))
+            })
+            .collect()
+    }
+
+    /// Counts the rotations that leave the dial pointing at zero.
+    fn part1(rotations: &Self::Parsed) -> Answer {
+        let mut dial = Dial::default();
+        rotations
+            .iter()
+            .filter(|rotation| {
+                dial.turn(rotation);
+                dial.position == 0
+            })
+            .count()
+            .into()
+    }
+
+    /// Counts every click at which the dial passes or lands on zero.
+    fn part2(rotations: &Self::Parsed) -> Answer {
+        let mut dial = Dial::default();
+        rotations
+            .iter()
+            .enumerate()
+            .map(|(i, rotation)| {
+                let from = dial.position;
+                let zero_crossings = dial.turn(rotation);
+                debug!(
+                    step = i,
+                    from,
+                    direction = %rotation.direction.to_string().to_lowercase(),
+                    clicks = rotation.steps,
+                    to = dial.position,
+                    zero_crossings,
+                    "turned dial"
+                );
+                u64::from(zero_crossings)
+            })
+            .sum::<u64>()
+            .into()
+    }
+}
+
+/// Day 1 turning the dial one click at a time: slower, but too simple to get wrong, so
+/// `aoc compare 1` can check [`Dial::turn`]'s arithmetic against it.
+pub struct Day1Clicks;
+
+crate::aoc_day!(1, Day1Clicks, variant = "clicks");
+
+impl Solution for Day1Clicks {
+    type Parsed = Vec<Rotation>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Day1::parse(input)
+    }
+
+    fn part1(rotations: &Self::Parsed) -> Answer {
+        Day1::part1(rotations)
+    }
+
+    fn part2(rotations: &Self::Parsed) -> Answer {
+        let (mut position, mut zeros) = (50, 0_u64);
+        for rotation in rotations {
+            for _ in 0..rotation.steps {
+                position = (position + rotation.direction as i32).rem_euclid(100);
+                zeros += u64::from(position == 0);
+            }
+        }
+        zeros.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    
+    use crate::days::day1::Rotation;
+    use crate::days::day1::Direction;
+    use crate::days::day1::Dial;
+
+    #[test]
+    fn parse_succeeds() {
+        let line = "L50";
+        assert_eq!(
+            Rotation::try_from(line),
+            Ok(Rotation {
+                direction: Direction::Left,
+                steps: 50
+            })
+        );
+
+        let line = "R1220";
+        assert_eq!(
+            Rotation::try_from(line),
+            Ok(Rotation {
+                direction: Direction::Right,
+                steps: 1220
+            })
+        );
+    }
+
+    #[test]
+    fn parse_fails() {
+
+        assert_eq!(
+            Rotation::try_from("X90"),
+            Err(crate::days::day1::RotationParseError::IncorrectStartOfLineCharacter)
+        );
+
+        assert!(matches!(
+            Rotation::try_from("LYY"),
+            Err(crate::days::day1::RotationParseError::ParseIntError(_))
+        ));
+
+    }
+
+    #[test]
+    fn test_zero_crossings() {
+
+        let mut dial = Dial::default();
+
+        assert_eq!(dial.turn(&Rotation::try_from("L50").unwrap()), 1); // 50 L50 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("L100").unwrap()), 1); // 0 L100 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("R100").unwrap()), 1); // 0 R100 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("R200").unwrap()), 2); // 0 R100 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("L200").unwrap()), 2); // 0 R100 = 0, one ZC
+
+        assert_eq!(dial.turn(&Rotation::try_from("L1").unwrap()), 0); // 0 L1 = 99, no ZC
+        assert_eq!(dial.turn(&Rotation::try_from("R1").unwrap()), 1); // 99 R1 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("L1").unwrap()), 0); // 0 L1 = 99, no ZC
+        assert_eq!(dial.turn(&Rotation::try_from("R101").unwrap()), 2); // 0 R101 = 0, two ZC
+
+        assert_eq!(dial.turn(&Rotation::try_from("R1").unwrap()), 0); // 0 R1 = 1, no ZC
+        assert_eq!(dial.turn(&Rotation::try_from("L1").unwrap()), 1); // 1 L1 = 0, one ZC
+        assert_eq!(dial.turn(&Rotation::try_from("R1").unwrap()), 0); // 0 R1 = 1, no ZC
+        assert_eq!(dial.turn(&Rotation::try_from("L101").unwrap()), 2); // 1 L101 = 0, two ZC
+
+        // Given example
+        let mut dial = Dial::default();
+        assert_eq!(dial.turn(&Rotation::try_from("L68").unwrap()), 1);
+        assert_eq!(dial.turn(&Rotation::try_from("L30").unwrap()), 0);
+        assert_eq!(dial.turn(&Rotation::try_from("R48").unwrap()), 1);
+        assert_eq!(dial.turn(&Rotation::try_from("L5").unwrap()), 0);
+        assert_eq!(dial.turn(&Rotation::try_from("R60").unwrap()), 1);
+        assert_eq!(dial.turn(&Rotation::try_from("L55").unwrap()), 1);
+        assert_eq!(dial.turn(&Rotation::try_from("L1").unwrap()), 0);
+        assert_eq!(dial.turn(&Rotation::try_from("L99").unwrap()), 1);
+        assert_eq!(dial.turn(&Rotation::try_from("R14").unwrap()), 0);
+        assert_eq!(dial.turn(&Rotation::try_from("L82").unwrap()), 1);
+
+    }
+
+    #[test]
+    fn example_answers() {
+        use crate::solution::Solution;
+
+        let rotations = crate::days::day1::Day1::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+        assert_eq!(crate::days::day1::Day1::part1(&rotations), "3");
+        assert_eq!(crate::days::day1::Day1::part2(&rotations), "6");
+        assert_eq!(crate::days::day1::solve("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n"), (3_u64.into(), 6_u64.into()));
+    }
+
+    #[test]
+    fn clicking_agrees_with_turning() {
+        use crate::days::day1::{Day1, Day1Clicks};
+        use crate::solution::Solution;
+
+        let rotations = Day1::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\nL50\nR200\nL101\n");
+        assert_eq!(Day1Clicks::part2(&rotations), Day1::part2(&rotations));
+    }
+
+}
@@ -0,0 +1,142 @@
+use crate::{
+    calendar,
+    solution::{RunOptions, RunResult, Solution, run},
+};
+
+/// Declares each day's module behind its `dayN` cargo feature, and records which ones made it
+/// into this build for [`compiled_out`].
+macro_rules! day_modules {
+    ($($number:literal => $module:ident, $feature:literal;)*) => {
+        $(
+            #[cfg(feature = $feature)]
+            pub mod $module;
+        )*
+
+        const MODULES: &[(u8, bool)] = &[$(($number, cfg!(feature = $feature))),*];
+    };
+}
+
+day_modules! {
+    1 => day1, "day1";
+}
+
+/// A registered day as seen by the runner.
+pub struct Day {
+    pub year: i32,
+    pub number: u8,
+    /// Empty for the day's main solution, or the name of an alternative implementation.
+    pub variant: &'static str,
+    pub run: fn(&str, &[u8], &RunOptions) -> RunResult,
+}
+
+impl Day {
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new<S: Solution + 'static>(year: i32, number: u8, variant: &'static str) -> Self {
+        Self { year, number, variant, run: run::<S> }
+    }
+
+    /// The variant's name, or `main` for the main solution.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        if self.variant.is_empty() { "main" } else { self.variant }
+    }
+}
+
+inventory::collect!(Day);
+
+/// Registers a `Solution` implementation as the given day, e.g. `aoc_day!(1, Day1);`, or as a
+/// day of an earlier event, e.g. `aoc_day!(2024, 1, Day1);`.
+///
+/// Alternative implementations for `aoc compare` are registered with a name:
+/// `aoc_day!(1, Day1Naive, variant = "naive");`.
+#[macro_export]
+macro_rules! aoc_day {
+    ($year:literal, $number:literal, $solution:ty, variant = $variant:literal) => {
+        $crate::inventory::submit! {
+            $crate::days::Day::new::<$solution>($year, $number, $variant)
+        }
+    };
+    ($number:literal, $solution:ty, variant = $variant:literal) => {
+        $crate::inventory::submit! {
+            $crate::days::Day::new::<$solution>($crate::calendar::YEAR, $number, $variant)
+        }
+    };
+    ($year:literal, $number:literal, $solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::days::Day::new::<$solution>($year, $number, "")
+        }
+    };
+    ($number:literal, $solution:ty) => {
+        $crate::inventory::submit! {
+            $crate::days::Day::new::<$solution>($crate::calendar::YEAR, $number, "")
+        }
+    };
+}
+
+/// Every day registered for the event being solved, in day order, without alternative
+/// implementations.
+#[must_use]
+pub fn all() -> Vec<&'static Day> {
+    let year = calendar::year();
+    let mut days: Vec<_> =
+        inventory::iter::<Day>.into_iter().filter(|day| day.year == year && day.variant.is_empty()).collect();
+    days.sort_by_key(|day| day.number);
+    days
+}
+
+/// `number`'s main solution for the event being solved.
+#[must_use]
+pub fn find(number: u8) -> Option<&'static Day> {
+    variants(number).into_iter().find(|day| day.variant.is_empty())
+}
+
+/// Every implementation of `number` for the event being solved: the main one first, then the
+/// alternatives by name.
+#[must_use]
+pub fn variants(number: u8) -> Vec<&'static Day> {
+    let year = calendar::year();
+    let mut days: Vec<_> =
+        inventory::iter::<Day>.into_iter().filter(|day| day.year == year && day.number == number).collect();
+    days.sort_by_key(|day| (!day.variant.is_empty(), day.variant));
+    days
+}
+
+/// This event's days that have a module but were left out of the build by their `dayN` feature.
+#[must_use]
+pub fn compiled_out() -> Vec<u8> {
+    if calendar::year() != calendar::YEAR {
+        return Vec::new();
+    }
+    MODULES.iter().filter(|(_, compiled)| !compiled).map(|&(number, _)| number).collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn days_are_registered_in_order() {
+        let numbers: Vec<u8> = crate::days::all().iter().map(|day| day.number).collect();
+        assert!(numbers.contains(&1));
+        assert!(numbers.is_sorted());
+        assert!(crate::days::find(1).is_some());
+        assert!(crate::days::find(0).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "day1")]
+    fn variants_follow_the_main_solution() {
+        let names: Vec<&str> = crate::days::variants(1).iter().map(|day| day.name()).collect();
+        assert_eq!(names, vec!["main", "clicks"]);
+        assert_eq!(crate::days::find(1).unwrap().variant, "");
+        assert_eq!(crate::days::all().iter().filter(|day| day.number == 1).count(), 1);
+    }
+
+    #[test]
+    fn compiled_out_days_match_their_features() {
+        assert_eq!(crate::days::compiled_out().contains(&1), !cfg!(feature = "day1"));
+        assert!(crate::days::compiled_out().iter().all(|&day| crate::days::find(day).is_none()));
+    }
+
+}
@@ -0,0 +1,116 @@
+use std::{fmt, fs, io, path::{Path, PathBuf}, time::SystemTime};
+
+use crate::{calendar, config, net};
+
+#[derive(Debug)]
+pub enum FetchError {
+    NotYetUnlocked { day: u8 },
+    MissingSession,
+    Http(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotYetUnlocked { day } => write!(f, "day {day} has not unlocked yet"),
+            Self::MissingSession if cfg!(feature = "keyring") => {
+                write!(f, "run `aoc login`, or set AOC_SESSION (or session_file in .aoc.toml), to give your adventofcode.com session cookie")
+            }
+            Self::MissingSession => write!(f, "set AOC_SESSION (or session_file in .aoc.toml) to your adventofcode.com session cookie"),
+            Self::Http(e) => write!(f, "request failed: {e}"),
+            Self::Io(e) => write!(f, "local file error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// The outcome of a successful fetch.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    AlreadyPresent(PathBuf),
+}
+
+/// Downloads `day`'s input to its default path using the session cookie.
+///
+/// Existing inputs are never re-downloaded, and nothing is requested before the puzzle unlocks.
+#[allow(clippy::missing_errors_doc)]
+pub fn fetch_input(day: u8) -> Result<Fetched, FetchError> {
+    let session = session();
+    fetch_input_to(day, &crate::default_input(day), SystemTime::now(), session.as_deref())
+}
+
+/// The session cookie: `$AOC_SESSION`, then the one `aoc login` saved in the OS keyring, then the
+/// contents of `session_file` from `.aoc.toml`.
+pub(crate) fn session() -> Option<String> {
+    std::env::var("AOC_SESSION").ok().filter(|s| !s.is_empty()).or_else(stored_session).or_else(|| {
+        let path = config::resolve(crate::config::get().session_file.as_deref()?);
+        Some(fs::read_to_string(path).ok()?.trim().to_owned()).filter(|s| !s.is_empty())
+    })
+}
+
+#[cfg(feature = "keyring")]
+fn stored_session() -> Option<String> {
+    crate::credentials::load()
+}
+
+#[cfg(not(feature = "keyring"))]
+const fn stored_session() -> Option<String> {
+    None
+}
+
+pub(crate) fn require_session(session: Option<&str>) -> Result<&str, FetchError> {
+    session.filter(|s| !s.is_empty()).ok_or(FetchError::MissingSession)
+}
+
+fn fetch_input_to(day: u8, path: &Path, now: SystemTime, session: Option<&str>) -> Result<Fetched, FetchError> {
+    if path.exists() {
+        return Ok(Fetched::AlreadyPresent(path.to_path_buf()));
+    }
+    if !calendar::is_unlocked(day, now) {
+        return Err(FetchError::NotYetUnlocked { day });
+    }
+    let session = require_session(session)?;
+
+    let url = format!("https://adventofcode.com/{}/day/{day}/input", calendar::year());
+    let input = net::client().get(&url, Some(session))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(FetchError::Io)?;
+    }
+    fs::write(path, input).map_err(FetchError::Io)?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, SystemTime};
+
+    use crate::{calendar, fetch::{FetchError, Fetched, fetch_input_to}, test::write_temp};
+
+    #[test]
+    fn existing_input_is_not_refetched() {
+        let path = write_temp("fetch-existing.txt", "L1\n");
+        assert_eq!(fetch_input_to(1, &path, SystemTime::UNIX_EPOCH, None).unwrap(), Fetched::AlreadyPresent(path));
+    }
+
+    #[test]
+    fn locked_day_is_refused() {
+        let path = std::env::temp_dir().join("aoc2025-never-created.txt");
+        let before_unlock = calendar::unlock_time(3) - Duration::from_mins(1);
+        assert!(matches!(fetch_input_to(3, &path, before_unlock, Some("abc")), Err(FetchError::NotYetUnlocked { day: 3 })));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn session_is_required() {
+        let path = std::env::temp_dir().join("aoc2025-never-created.txt");
+        let after_unlock = calendar::unlock_time(3);
+        assert!(matches!(fetch_input_to(3, &path, after_unlock, None), Err(FetchError::MissingSession)));
+        assert!(matches!(fetch_input_to(3, &path, after_unlock, Some("")), Err(FetchError::MissingSession)));
+    }
+
+}
@@ -0,0 +1,117 @@
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+use serde::Deserialize;
+
+use crate::{calendar, fetch::{FetchError, require_session, session}, net};
+
+/// How long a downloaded leaderboard is reused; the site asks for no more than one request per
+/// 15 minutes.
+pub const REFRESH_INTERVAL: Duration = Duration::from_mins(15);
+
+/// A private leaderboard, as served by its JSON API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Day → part → the star earned.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// When it was earned, in seconds since the Unix epoch.
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    /// The member's name, or `anonymous user #id` as the site shows it.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("anonymous user #{}", self.id))
+    }
+
+    /// Stars earned on `day`: 0, 1 or 2.
+    #[must_use]
+    pub fn stars_on(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+}
+
+impl Leaderboard {
+    /// Members by local score, highest first, then by stars and name.
+    #[must_use]
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            (b.local_score, b.stars).cmp(&(a.local_score, a.stars)).then_with(|| a.display_name().cmp(&b.display_name()))
+        });
+        members
+    }
+}
+
+/// One row per member: rank, local score, a `*` (both stars), `+` (one) or `.` per day, stars and name.
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let last_day = calendar::days_in(calendar::year());
+        let days: String = (1..=last_day).map(|day| char::from(b'0' + day % 10)).collect();
+        writeln!(f, "{:>4}  {:>5}  {days}  {:>5}  Name", "", "Score", "Stars")?;
+        for (rank, member) in self.ranked().into_iter().enumerate() {
+            let grid: String = (1..=last_day)
+                .map(|day| match member.stars_on(day) {
+                    0 => '.',
+                    1 => '+',
+                    _ => '*',
+                })
+                .collect();
+            let rank = format!("{})", rank + 1);
+            writeln!(f, "{rank:>4}  {:>5}  {grid}  {:>5}  {}", member.local_score, member.stars, member.display_name())?;
+        }
+        Ok(())
+    }
+}
+
+/// Private leaderboard `id` for this year's event.
+///
+/// The response is cached and reused for [`REFRESH_INTERVAL`]; after that it is downloaded again,
+/// falling back to the stale copy if the download fails.
+#[allow(clippy::missing_errors_doc)]
+pub fn leaderboard(id: u64) -> Result<Leaderboard, FetchError> {
+    let session = session();
+    let url = format!("https://adventofcode.com/{}/leaderboard/private/view/{id}.json", calendar::year());
+    let json = net::client().get_cached(&url, Some(require_session(session.as_deref())?), REFRESH_INTERVAL)?;
+    serde_json::from_str(&json).map_err(|e| FetchError::Http(format!("unexpected leaderboard response: {e}")))
+}
+
+#[cfg(test)]
+mod test {
+
+    const JSON: &str = r#"{"event":"2025","owner_id":1,"members":{
+        "1":{"id":1,"name":"Alice","stars":3,"local_score":9,"global_score":0,"last_star_ts":0,
+             "completion_day_level":{"1":{"1":{"get_star_ts":1,"star_index":0},"2":{"get_star_ts":2,"star_index":1}},
+                                     "3":{"1":{"get_star_ts":3,"star_index":2}}}},
+        "2":{"id":2,"name":null,"stars":0,"local_score":0,"global_score":0,"last_star_ts":0,"completion_day_level":{}}}}"#;
+
+    #[test]
+    fn members_are_ranked_and_rendered() {
+        let board: super::Leaderboard = serde_json::from_str(JSON).unwrap();
+        let ranked = board.ranked();
+        assert_eq!(ranked[0].display_name(), "Alice");
+        assert_eq!((ranked[0].stars_on(1), ranked[0].stars_on(2), ranked[0].stars_on(3)), (2, 0, 1));
+        assert_eq!(ranked[1].display_name(), "anonymous user #2");
+
+        let table = board.to_string();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "      Score  123456789012  Stars  Name");
+        assert_eq!(lines[1], "  1)      9  *.+.........      3  Alice");
+        assert_eq!(lines[2], "  2)      0  ............      0  anonymous user #2");
+    }
+
+}
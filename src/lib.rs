@@ -1,8 +1,76 @@
-use std::{fs::File, io::{self, BufRead}, path::Path};
-
-#[allow(clippy::missing_errors_doc)]
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
\ No newline at end of file
+pub mod answers;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod calendar;
+pub mod cli;
+pub mod config;
+#[cfg(feature = "keyring")]
+pub mod credentials;
+pub mod days;
+#[cfg(feature = "http")]
+pub mod fetch;
+#[cfg(feature = "http")]
+pub mod leaderboard;
+pub mod logging;
+#[cfg(feature = "alloc-stats")]
+pub mod memory;
+#[cfg(feature = "http")]
+pub mod net;
+pub mod progress;
+#[cfg(feature = "http")]
+pub mod puzzle;
+pub mod report;
+pub mod solution;
+pub mod style;
+#[cfg(feature = "http")]
+pub mod submit;
+
+#[doc(hidden)]
+pub use inventory;
+
+use std::path::{Path, PathBuf};
+
+/// The directory holding puzzle inputs: `$AOC_INPUT_DIR`, then `input_dir` from `.aoc.toml`,
+/// then `inputs/` in the crate root (`inputs/<year>/` for other events, see [`calendar::year_dir`]).
+#[must_use]
+pub fn input_dir() -> PathBuf {
+    let configured = || config::get().input_dir.as_deref().map(config::resolve);
+    std::env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .or_else(configured)
+        .unwrap_or_else(|| calendar::year_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")))
+}
+
+/// The conventional input path for `day`, e.g. `inputs/day1.txt`.
+#[must_use]
+pub fn default_input(day: u8) -> PathBuf {
+    input_dir().join(format!("day{day}.txt"))
+}
+
+/// The conventional path of `day`'s `n`th example input: `inputs/day1.example.txt` for the
+/// first, then `inputs/day1.example2.txt` and so on.
+#[must_use]
+pub fn example_input(day: u8, n: u8) -> PathBuf {
+    let suffix = if n == 1 { String::new() } else { n.to_string() };
+    input_dir().join(format!("day{day}.example{suffix}.txt"))
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::path::PathBuf;
+
+    pub fn write_temp(name: &str, contents: impl AsRef<[u8]>) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc2025-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn default_input_follows_day_convention() {
+        let path = crate::default_input(7);
+        assert_eq!(path.file_name().unwrap(), "day7.txt");
+        assert_eq!(path.parent().unwrap(), crate::input_dir());
+    }
+
+}
@@ -0,0 +1,46 @@
+use std::io::{self, IsTerminal};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// The level shown for a number of `-v` flags: warnings by default, then `info`, `debug` and
+/// `trace`.
+#[must_use]
+pub const fn level(verbose: u8) -> LevelFilter {
+    match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Sends `tracing` events to stderr, filtered by `RUST_LOG` when it is set and by the `-v` count
+/// otherwise, leaving stdout to the answers.
+///
+/// Colours follow [`style::enabled`](crate::style::enabled), so call this after any `--no-color`.
+pub fn init(verbose: u8) {
+    let filter = EnvFilter::builder().with_default_directive(level(verbose).into()).from_env_lossy();
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(crate::style::enabled() && io::stderr().is_terminal())
+        .try_init();
+}
+
+#[cfg(test)]
+mod test {
+
+    use tracing::level_filters::LevelFilter;
+
+    use crate::logging::level;
+
+    #[test]
+    fn verbosity_raises_the_level() {
+        assert_eq!(level(0), LevelFilter::WARN);
+        assert_eq!(level(1), LevelFilter::INFO);
+        assert_eq!(level(2), LevelFilter::DEBUG);
+        assert_eq!(level(7), LevelFilter::TRACE);
+    }
+
+}
@@ -0,0 +1,182 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config, fetch::FetchError};
+
+/// The least time between two requests to adventofcode.com, across every `aoc` process.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Identifies the runner to adventofcode.com, as its maintainers ask of automated tools, with
+/// `contact` from `.aoc.toml` so they can reach whoever is running it.
+#[must_use]
+pub fn user_agent() -> String {
+    let agent = concat!("github.com/gdyr/aoc2025 v", env!("CARGO_PKG_VERSION"), " via ureq");
+    config::get().contact.as_ref().map_or_else(|| agent.to_owned(), |contact| format!("{agent} (contact: {contact})"))
+}
+
+/// The HTTP client every command that talks to adventofcode.com goes through: one agent with
+/// [`user_agent`], requests spaced by [`MIN_INTERVAL`], and an on-disk response cache.
+pub struct Client {
+    agent: ureq::Agent,
+    /// Holds `last-request`, the time of the latest request, and the `http/` response cache.
+    dir: PathBuf,
+    min_interval: Duration,
+}
+
+/// A response kept by [`Client::get_cached`], with when it was fetched.
+#[derive(Serialize, Deserialize)]
+struct Cached {
+    url: String,
+    fetched_secs: u64,
+    body: String,
+}
+
+/// The shared client, keeping its state in `.aoc/` in the crate root.
+#[must_use]
+pub fn client() -> &'static Client {
+    static CLIENT: LazyLock<Client> =
+        LazyLock::new(|| Client::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc"), MIN_INTERVAL));
+    &CLIENT
+}
+
+impl Client {
+    fn new(dir: PathBuf, min_interval: Duration) -> Self {
+        let agent = ureq::Agent::config_builder().user_agent(user_agent()).build().into();
+        Self { agent, dir, min_interval }
+    }
+
+    /// Fetches `url`, sending the session cookie when there is one.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn get(&self, url: &str, session: Option<&str>) -> Result<String, FetchError> {
+        let mut request = self.agent.get(url);
+        if let Some(session) = session {
+            request = request.header("Cookie", &format!("session={session}"));
+        }
+        self.throttle();
+        request.call().and_then(|response| response.into_body().read_to_string()).map_err(|e| FetchError::Http(e.to_string()))
+    }
+
+    /// Like [`get`](Self::get), but reuses a response fetched less than `max_age` ago, and falls
+    /// back to an older one if the request fails.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn get_cached(&self, url: &str, session: Option<&str>, max_age: Duration) -> Result<String, FetchError> {
+        self.cached(url, SystemTime::now(), max_age, || self.get(url, session))
+    }
+
+    /// Posts `form` to `url` with the session cookie. Never cached.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        let request = self.agent.post(url).header("Cookie", &format!("session={session}"));
+        self.throttle();
+        request
+            .send_form(form.iter().copied())
+            .and_then(|response| response.into_body().read_to_string())
+            .map_err(|e| FetchError::Http(e.to_string()))
+    }
+
+    fn cached<F>(&self, url: &str, now: SystemTime, max_age: Duration, download: F) -> Result<String, FetchError>
+    where F: FnOnce() -> Result<String, FetchError>, {
+        let file = url.trim_start_matches("https://").replace(|c: char| !c.is_ascii_alphanumeric() && c != '.', "-");
+        let path = self.dir.join("http").join(format!("{file}.json"));
+        let cached: Option<Cached> =
+            fs::read(&path).ok().and_then(|bytes| serde_json::from_slice(&bytes).ok()).filter(|cached: &Cached| cached.url == url);
+        let age = |cached: &Cached| now.duration_since(SystemTime::UNIX_EPOCH + Duration::from_secs(cached.fetched_secs));
+
+        match cached {
+            Some(cached) if age(&cached).is_ok_and(|age| age < max_age) => Ok(cached.body),
+            cached => match download() {
+                Ok(body) => {
+                    let fetched_secs = now.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |since| since.as_secs());
+                    let entry = Cached { url: url.to_owned(), fetched_secs, body };
+                    fs::create_dir_all(self.dir.join("http")).map_err(FetchError::Io)?;
+                    fs::write(&path, serde_json::to_vec(&entry).map_err(|e| FetchError::Io(e.into()))?).map_err(FetchError::Io)?;
+                    Ok(entry.body)
+                }
+                Err(e) => cached.map(|cached| cached.body).ok_or(e),
+            },
+        }
+    }
+
+    /// Sleeps until [`MIN_INTERVAL`] has passed since the last request any process recorded, then
+    /// records this one. Failing to record it only costs the next request its wait.
+    fn throttle(&self) {
+        let path = self.dir.join("last-request");
+        let last = fs::read_to_string(&path).ok().and_then(|millis| millis.trim().parse().ok()).map(Duration::from_millis);
+        let since_epoch = || SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default();
+        thread::sleep(wait(last, since_epoch(), self.min_interval));
+
+        let _ = fs::create_dir_all(&self.dir).and_then(|()| fs::write(&path, since_epoch().as_millis().to_string()));
+    }
+}
+
+/// How long to hold a request made at `now` (both since the epoch) that must follow the one
+/// made at `last` by at least `min_interval`.
+fn wait(last: Option<Duration>, now: Duration, min_interval: Duration) -> Duration {
+    last.map_or(Duration::ZERO, |last| (last + min_interval).saturating_sub(now).min(min_interval))
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, SystemTime};
+
+    use crate::{
+        fetch::FetchError,
+        net::{Client, wait},
+    };
+
+    #[test]
+    fn requests_wait_out_the_interval() {
+        let interval = Duration::from_secs(3);
+        let at = Duration::from_secs;
+        assert_eq!(wait(None, at(100), interval), Duration::ZERO);
+        assert_eq!(wait(Some(at(99)), at(100), interval), at(2));
+        assert_eq!(wait(Some(at(90)), at(100), interval), Duration::ZERO);
+        // A clock that went backwards never waits more than one interval.
+        assert_eq!(wait(Some(at(200)), at(100), interval), interval);
+    }
+
+    #[test]
+    fn throttling_records_the_request() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-throttle", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let client = Client::new(dir.clone(), Duration::from_millis(50));
+
+        client.throttle();
+        let start = std::time::Instant::now();
+        client.throttle();
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert!(dir.join("last-request").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn responses_are_cached_until_they_age() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-http-cache", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let client = Client::new(dir.clone(), Duration::ZERO);
+        let (url, max_age, now) = ("https://adventofcode.com/2025/leaderboard/private/view/1.json", Duration::from_mins(15), SystemTime::now());
+        let offline = || Err(FetchError::Http("offline".to_owned()));
+
+        assert!(client.cached(url, now, max_age, offline).is_err());
+        assert_eq!(client.cached(url, now, max_age, || Ok("first".to_owned())).unwrap(), "first");
+        // Fresh: not downloaded again.
+        assert_eq!(client.cached(url, now, max_age, || panic!("downloaded a fresh response")).unwrap(), "first");
+        // Stale: downloaded again, or the old copy if that fails.
+        let later = now + max_age + Duration::from_secs(1);
+        assert_eq!(client.cached(url, later, max_age, || Ok("second".to_owned())).unwrap(), "second");
+        assert_eq!(client.cached(url, later, max_age, offline).unwrap(), "second");
+        // Other URLs have their own entries.
+        assert!(client.cached("https://adventofcode.com/2025/day/1", now, max_age, offline).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+}
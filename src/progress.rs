@@ -0,0 +1,162 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::calendar;
+
+/// A part whose answer was confirmed, by `answers.toml` or by the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Solved {
+    pub answer: String,
+    /// The fastest run seen with this answer, in nanoseconds.
+    pub best_ns: Option<u64>,
+    /// When it was first confirmed, in seconds since the Unix epoch.
+    pub at: u64,
+}
+
+impl Solved {
+    #[must_use]
+    pub fn best(&self) -> Option<Duration> {
+        self.best_ns.map(Duration::from_nanos)
+    }
+}
+
+/// Which parts are solved, persisted as JSON in `.aoc/progress.json` (`.aoc/<year>/` for other events).
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    days: BTreeMap<u8, BTreeMap<u8, Solved>>,
+}
+
+impl Progress {
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        calendar::year_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc")).join("progress.json")
+    }
+
+    /// Loads the progress, treating a missing file as no progress.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::from),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+
+    #[must_use]
+    pub fn solved(&self, day: u8, part: u8) -> Option<&Solved> {
+        self.days.get(&day)?.get(&part)
+    }
+
+    #[must_use]
+    pub fn stars(&self) -> usize {
+        self.days.values().map(BTreeMap::len).sum()
+    }
+
+    /// Marks a part solved with `answer`, keeping the best time. A different answer (say, after
+    /// fixing a bug that happened to pass) starts over.
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, elapsed: Option<Duration>, now: SystemTime) {
+        let elapsed_ns = elapsed.map(|elapsed| u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX));
+        let at = now.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let solved = self
+            .days
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .and_modify(|solved| {
+                if solved.answer != answer {
+                    *solved = Solved { answer: answer.to_owned(), best_ns: None, at };
+                }
+            })
+            .or_insert_with(|| Solved { answer: answer.to_owned(), best_ns: None, at });
+        solved.best_ns = match (solved.best_ns, elapsed_ns) {
+            (Some(best), Some(elapsed)) => Some(best.min(elapsed)),
+            (best, elapsed) => best.or(elapsed),
+        };
+    }
+}
+
+/// A row per day with its stars and each part's best time, then the star count.
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>3}  {:5}  {:>10}  {:>10}", "Day", "Stars", "Part 1", "Part 2")?;
+        let last_day = calendar::days_in(calendar::year());
+        for day in 1..=last_day {
+            let part = |part| self.solved(day, part);
+            let stars: String = [part(1), part(2)].iter().map(|solved| if solved.is_some() { '*' } else { '.' }).collect();
+            let best = |part| {
+                self.solved(day, part).map_or_else(String::new, |solved| {
+                    solved.best().map_or_else(|| "solved".to_owned(), |best| format!("{best:.2?}"))
+                })
+            };
+            let row = format!("{day:>3}  {stars:5}  {:>10}  {:>10}", best(1), best(2));
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        write!(f, "{}/{} stars", self.stars(), 2 * usize::from(last_day))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, SystemTime};
+
+    use crate::progress::Progress;
+
+    #[test]
+    fn best_times_are_kept_per_answer() {
+        let mut progress = Progress::default();
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100);
+        progress.record(1, 1, "3", Some(Duration::from_micros(5)), now);
+        progress.record(1, 1, "3", Some(Duration::from_micros(9)), now + Duration::from_secs(1));
+        progress.record(1, 2, "6", None, now);
+        assert_eq!(progress.solved(1, 1).unwrap().best(), Some(Duration::from_micros(5)));
+        assert_eq!(progress.solved(1, 1).unwrap().at, 100);
+        assert_eq!(progress.solved(1, 2).unwrap().best(), None);
+        assert!(progress.solved(2, 1).is_none());
+        assert_eq!(progress.stars(), 2);
+
+        progress.record(1, 1, "4", Some(Duration::from_micros(7)), now);
+        assert_eq!(progress.solved(1, 1).unwrap().best(), Some(Duration::from_micros(7)));
+    }
+
+    #[test]
+    fn status_lists_every_day() {
+        let mut progress = Progress::default();
+        progress.record(2, 1, "10", Some(Duration::from_millis(12)), SystemTime::now());
+        let status = progress.to_string();
+        let lines: Vec<&str> = status.lines().collect();
+        assert_eq!(lines[0], "Day  Stars      Part 1      Part 2");
+        assert_eq!(lines[1], "  1  ..");
+        assert_eq!(lines[2], "  2  *.        12.00ms");
+        assert_eq!(lines.last(), Some(&"1/24 stars"));
+    }
+
+    #[test]
+    fn progress_round_trips() {
+        let path = crate::test::write_temp("progress.json", "");
+        assert!(Progress::load(&path).is_err());
+
+        let mut progress = Progress::default();
+        progress.record(3, 2, "42", Some(Duration::from_nanos(1500)), SystemTime::now());
+        progress.save(&path).unwrap();
+        assert_eq!(Progress::load(&path).unwrap(), progress);
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+    }
+
+}
@@ -0,0 +1,169 @@
+use std::{fs, path::{Path, PathBuf}, time::SystemTime};
+
+use crate::{calendar, fetch::{FetchError, session}, net};
+
+/// Where puzzle descriptions are cached: `.aoc/puzzles/` in the crate root, under the year for
+/// other events.
+#[must_use]
+pub fn puzzle_dir() -> PathBuf {
+    calendar::year_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc")).join("puzzles")
+}
+
+/// `day`'s puzzle description as markdown, one entry per part unlocked so far.
+///
+/// Each part is cached once seen, so a fully solved day is never downloaded again and a
+/// cached description is still returned when offline. The session cookie is sent if there is one,
+/// which is what makes part two visible.
+#[allow(clippy::missing_errors_doc)]
+pub fn description(day: u8) -> Result<Vec<String>, FetchError> {
+    let session = session();
+    description_in(&puzzle_dir(), day, SystemTime::now(), || download(day, session.as_deref()))
+}
+
+fn download(day: u8, session: Option<&str>) -> Result<String, FetchError> {
+    let url = format!("https://adventofcode.com/{}/day/{day}", calendar::year());
+    net::client().get(&url, session)
+}
+
+fn description_in<F>(dir: &Path, day: u8, now: SystemTime, download: F) -> Result<Vec<String>, FetchError>
+where F: FnOnce() -> Result<String, FetchError>, {
+    let part_path = |part: usize| dir.join(format!("day{day}-part{part}.md"));
+    let cached: Vec<String> = (1..=2).map_while(|part| fs::read_to_string(part_path(part)).ok()).collect();
+    if cached.len() == 2 {
+        return Ok(cached);
+    }
+    if !calendar::is_unlocked(day, now) {
+        return Err(FetchError::NotYetUnlocked { day });
+    }
+
+    let html = match download() {
+        Ok(html) => html,
+        Err(_) if !cached.is_empty() => return Ok(cached),
+        Err(e) => return Err(e),
+    };
+    let parts: Vec<String> = articles(&html).map(to_markdown).collect();
+    fs::create_dir_all(dir).map_err(FetchError::Io)?;
+    for (i, part) in parts.iter().enumerate() {
+        fs::write(part_path(i + 1), part).map_err(FetchError::Io)?;
+    }
+    Ok(parts)
+}
+
+/// The `<article class="day-desc">` bodies of a puzzle page, one per part.
+fn articles(html: &str) -> impl Iterator<Item = &str> {
+    const OPEN: &str = "<article class=\"day-desc\">";
+    html.split(OPEN).skip(1).filter_map(|rest| rest.find("</article>").map(|end| &rest[..end]))
+}
+
+/// Renders the small subset of HTML used in puzzle descriptions as markdown.
+#[must_use]
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+        let Some(end) = rest[start..].find('>') else { break };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let closing = tag.starts_with('/');
+        let name = tag.trim_start_matches('/').split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or("");
+        match (name, closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) | ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+    out.push_str(&decode_entities(rest));
+
+    let mut markdown = out.trim_end().to_owned();
+    markdown.push('\n');
+    markdown
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::SystemTime;
+
+    use crate::{calendar, fetch::FetchError, puzzle::{description_in, to_markdown}};
+
+    const PAGE: &str = concat!(
+        "<main><article class=\"day-desc\"><h2>--- Day 1: Dial ---</h2><p>Turn the <em>dial</em> &amp; ",
+        "count <code>0</code>s.</p><pre><code>L68\nL<em>30</em>\n</code></pre><ul><li>one</li><li>two</li></ul></article>",
+        "<p>Your puzzle answer was <code>3</code>.</p>",
+        "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Count &lt;clicks&gt;.</p></article></main>",
+    );
+
+    #[test]
+    fn html_becomes_markdown() {
+        let parts: Vec<String> = super::articles(PAGE).map(to_markdown).collect();
+        assert_eq!(
+            parts,
+            vec![
+                "## --- Day 1: Dial ---\n\nTurn the *dial* & count `0`s.\n\n```\nL68\nL30\n```\n\n- one\n- two\n".to_owned(),
+                "## --- Part Two ---\n\nCount <clicks>.\n".to_owned(),
+            ]
+        );
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn parts_are_cached() {
+        let dir = temp_dir("puzzle-cache");
+        let now = calendar::unlock_time(1);
+        let first_part_only = PAGE.split("<p>Your puzzle").next().unwrap().to_owned();
+
+        assert_eq!(description_in(&dir, 1, now, || Ok(first_part_only)).unwrap().len(), 1);
+        // Offline with one part cached: the cached part is returned.
+        assert_eq!(description_in(&dir, 1, now, || Err(FetchError::Http("offline".to_owned()))).unwrap().len(), 1);
+        // Part two appears once unlocked, after which nothing is downloaded again.
+        assert_eq!(description_in(&dir, 1, now, || Ok(PAGE.to_owned())).unwrap().len(), 2);
+        assert_eq!(description_in(&dir, 1, now, || panic!("should not download")).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn locked_and_offline_days_fail() {
+        let dir = temp_dir("puzzle-locked");
+        assert!(matches!(
+            description_in(&dir, 5, SystemTime::UNIX_EPOCH, || Ok(PAGE.to_owned())),
+            Err(FetchError::NotYetUnlocked { day: 5 })
+        ));
+        assert!(matches!(
+            description_in(&dir, 5, calendar::unlock_time(5), || Err(FetchError::Http("offline".to_owned()))),
+            Err(FetchError::Http(_))
+        ));
+    }
+
+}
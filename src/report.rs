@@ -0,0 +1,326 @@
+use std::{fmt::Write, path::PathBuf, time::Duration};
+
+use crate::{
+    answers::Check,
+    solution::{Answer, Phase, RunResult},
+};
+
+/// What the runner made of one day, for a [`Report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub input: PathBuf,
+    /// `None` when there was no input to run on.
+    pub result: Option<RunResult>,
+    /// How each of `result`'s parts compared with `answers.toml`, in the same order.
+    pub checks: Vec<Check>,
+}
+
+impl DayReport {
+    fn cell(&self, part: u8) -> String {
+        let Some(result) = &self.result else { return String::new() };
+        let failed = |phase: &Phase| *phase == Phase::Parse || *phase == Phase::Part(part);
+        match result.parts.iter().position(|solved| solved.part == part) {
+            Some(i) => format!("{}{}", result.parts[i].answer.summary(), mark(self.checks.get(i))),
+            None if result.panicked.is_some_and(|phase| failed(&phase)) => "panicked".to_owned(),
+            None if result.timed_out.is_some_and(|phase| failed(&phase)) => "timed out".to_owned(),
+            None => String::new(),
+        }
+    }
+
+    fn elapsed(&self, part: u8) -> String {
+        let part = self.result.iter().flat_map(|result| &result.parts).find(|solved| solved.part == part);
+        part.map_or_else(String::new, |part| time(part.elapsed))
+    }
+}
+
+/// The results of running every day, rendered as markdown or HTML: a summary table, then a
+/// section per day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: i32,
+    pub days: Vec<DayReport>,
+}
+
+/// The pieces both renderings are built from.
+enum Block {
+    Heading { level: u8, id: Option<String>, text: String },
+    Paragraph(String),
+    /// The first column is left-aligned, the rest right-aligned.
+    Table { header: Vec<String>, rows: Vec<Vec<String>> },
+    /// Preformatted lines, such as a grid answer.
+    Code(String),
+}
+
+impl Report {
+    /// The number of parts whose answer matched `answers.toml`, and of parts solved.
+    #[must_use]
+    pub fn verified(&self) -> (usize, usize) {
+        let checks = self.days.iter().flat_map(|day| &day.checks);
+        (checks.clone().filter(|check| **check == Check::Pass).count(), checks.count())
+    }
+
+    #[must_use]
+    pub fn markdown(&self) -> String {
+        let mut out = String::new();
+        for block in self.blocks() {
+            match block {
+                Block::Heading { level, text, .. } => {
+                    let _ = writeln!(out, "{} {text}\n", "#".repeat(usize::from(level)));
+                }
+                Block::Paragraph(text) => {
+                    let _ = writeln!(out, "{text}\n");
+                }
+                Block::Table { header, rows } => {
+                    let row = |cells: &[String]| format!("| {} |", cells.iter().map(|cell| cell.replace('|', "\\|")).collect::<Vec<_>>().join(" | "));
+                    let rule: Vec<&str> = (0..header.len()).map(|i| if i == 0 { ":--" } else { "--:" }).collect();
+                    let _ = writeln!(out, "{}\n| {} |", row(&header), rule.join(" | "));
+                    for cells in rows {
+                        let _ = writeln!(out, "{}", row(&cells));
+                    }
+                    out.push('\n');
+                }
+                Block::Code(text) => {
+                    let _ = writeln!(out, "```\n{text}\n```\n");
+                }
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    /// A standalone HTML page.
+    #[must_use]
+    pub fn html(&self) -> String {
+        let title = escape(&self.title());
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n"
+        );
+        for block in self.blocks() {
+            match block {
+                Block::Heading { level, id, text } => {
+                    let id = id.map_or_else(String::new, |id| format!(" id=\"{id}\""));
+                    let _ = writeln!(out, "<h{level}{id}>{}</h{level}>", escape(&text));
+                }
+                Block::Paragraph(text) => {
+                    let _ = writeln!(out, "<p>{}</p>", escape(&text));
+                }
+                Block::Table { header, rows } => {
+                    out.push_str("<table>\n<tr>");
+                    for cell in header {
+                        let _ = write!(out, "<th>{}</th>", escape(&cell));
+                    }
+                    out.push_str("</tr>\n");
+                    for cells in rows {
+                        out.push_str("<tr>");
+                        for cell in cells {
+                            let _ = write!(out, "<td>{}</td>", escape(&cell));
+                        }
+                        out.push_str("</tr>\n");
+                    }
+                    out.push_str("</table>\n");
+                }
+                Block::Code(text) => {
+                    let _ = writeln!(out, "<pre>{}</pre>", escape(&text));
+                }
+            }
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    fn title(&self) -> String {
+        format!("Advent of Code {} results", self.year)
+    }
+
+    fn blocks(&self) -> Vec<Block> {
+        let total: Duration = self.days.iter().filter_map(|day| day.result.as_ref()).map(RunResult::total).sum();
+        let run = self.days.iter().filter(|day| day.result.is_some()).count();
+        let (verified, solved) = self.verified();
+
+        let mut blocks = vec![
+            Block::Heading { level: 1, id: None, text: self.title() },
+            Block::Paragraph(format!(
+                "{run} day(s) run in {} in total; {verified} of {solved} answers verified against answers.toml.",
+                time(total)
+            )),
+            Block::Table {
+                header: ["Day", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total"].map(str::to_owned).to_vec(),
+                rows: self
+                    .days
+                    .iter()
+                    .map(|day| {
+                        let Some(result) = &day.result else {
+                            return vec![day.day.to_string(), "no input".to_owned()];
+                        };
+                        vec![
+                            day.day.to_string(),
+                            day.cell(1),
+                            day.cell(2),
+                            parse_time(result),
+                            day.elapsed(1),
+                            day.elapsed(2),
+                            time(result.total()),
+                        ]
+                    })
+                    .collect(),
+            },
+        ];
+
+        for day in &self.days {
+            blocks.push(Block::Heading { level: 2, id: Some(format!("day-{}", day.day)), text: format!("Day {}", day.day) });
+            let Some(result) = &day.result else {
+                blocks.push(Block::Paragraph(format!("No input at {}.", day.input.display())));
+                continue;
+            };
+            blocks.push(Block::Paragraph(format!("Input: {}", day.input.display())));
+            let mut rows = vec![vec!["Parse".to_owned(), String::new(), String::new(), parse_time(result)]];
+            // Multi-line answers don't fit in a table row, so they follow it in full.
+            let mut grids = Vec::new();
+            for (i, part) in result.parts.iter().enumerate() {
+                if let Answer::Grid(_) = part.answer {
+                    grids.push((format!("Part {}:", part.part), part.answer.to_string()));
+                }
+                let verified = match day.checks.get(i) {
+                    Some(Check::Pass) => "yes".to_owned(),
+                    Some(Check::Fail { expected }) if expected.contains('\n') => {
+                        grids.push((format!("Part {} expected:", part.part), expected.trim_end().to_owned()));
+                        "no".to_owned()
+                    }
+                    Some(Check::Fail { expected }) => format!("no, expected {expected}"),
+                    Some(Check::Unknown) | None => String::new(),
+                };
+                rows.push(vec![format!("Part {}", part.part), part.answer.summary(), verified, time(part.elapsed)]);
+            }
+            if let Some(phase) = result.panicked.or(result.timed_out) {
+                let what = if result.panicked.is_some() { "panicked" } else { "timed out" };
+                let phase = match phase {
+                    Phase::Parse => "Parse".to_owned(),
+                    Phase::Part(part) => format!("Part {part}"),
+                };
+                rows.retain(|row| row[0] != phase);
+                rows.push(vec![phase, what.to_owned(), String::new(), String::new()]);
+            }
+            let header = ["Phase", "Answer", "Verified", "Time"].map(str::to_owned).to_vec();
+            blocks.push(Block::Table { header, rows });
+            for (label, text) in grids {
+                blocks.push(Block::Paragraph(label));
+                blocks.push(Block::Code(text));
+            }
+        }
+        blocks
+    }
+}
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: 2em auto; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; border-bottom: 1px solid #ddd; text-align: right; }
+th:first-child, td:first-child { text-align: left; }";
+
+/// ` ✓` for a verified answer, ` ✗` for a wrong one.
+const fn mark(check: Option<&Check>) -> &'static str {
+    match check {
+        Some(Check::Pass) => " ✓",
+        Some(Check::Fail { .. }) => " ✗",
+        Some(Check::Unknown) | None => "",
+    }
+}
+
+fn time(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+fn parse_time(result: &RunResult) -> String {
+    let parsed = result.panicked != Some(Phase::Parse) && result.timed_out != Some(Phase::Parse);
+    if parsed { time(result.parse_elapsed) } else { String::new() }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::{path::PathBuf, time::Duration};
+
+    use crate::{
+        answers::Check,
+        report::{DayReport, Report},
+        solution::{Answer, PartResult, Phase, RunResult},
+    };
+
+    fn report() -> Report {
+        let part = |part, answer: u64, micros| PartResult {
+            part,
+            answer: answer.into(),
+            elapsed: Duration::from_micros(micros),
+            peak_bytes: None,
+            flamegraph: None,
+        };
+        let solved = RunResult {
+            parse_elapsed: Duration::from_micros(10),
+            parts: vec![part(1, 3, 20), part(2, 6, 30)],
+            ..RunResult::default()
+        };
+        let crashed = RunResult { parts: vec![part(1, 7, 5)], panicked: Some(Phase::Part(2)), ..RunResult::default() };
+        Report {
+            year: 2025,
+            days: vec![
+                DayReport {
+                    day: 1,
+                    input: PathBuf::from("inputs/day1.txt"),
+                    result: Some(solved),
+                    checks: vec![Check::Pass, Check::Fail { expected: "<5>".to_owned() }],
+                },
+                DayReport { day: 2, input: PathBuf::from("inputs/day2.txt"), result: Some(crashed), checks: vec![Check::Unknown] },
+                DayReport { day: 3, input: PathBuf::from("inputs/day3.txt"), result: None, checks: vec![] },
+            ],
+        }
+    }
+
+    #[test]
+    fn markdown_has_a_summary_and_a_section_per_day() {
+        let markdown = report().markdown();
+        assert!(markdown.starts_with("# Advent of Code 2025 results\n\n2 day(s) run in 65.00µs in total; 1 of 3 answers verified"));
+        assert!(markdown.contains("| Day | Part 1 | Part 2 | Parse | Part 1 | Part 2 | Total |\n| :-- | --: |"));
+        assert!(markdown.contains("| 1 | 3 ✓ | 6 ✗ | 10.00µs | 20.00µs | 30.00µs | 60.00µs |"));
+        assert!(markdown.contains("| 2 | 7 | panicked | 0.00ns | 5.00µs |  | 5.00µs |"));
+        assert!(markdown.contains("| 3 | no input |"));
+        assert!(markdown.contains("## Day 1\n\nInput: inputs/day1.txt\n"));
+        assert!(markdown.contains("| Part 2 | 6 | no, expected <5> | 30.00µs |"));
+        assert!(markdown.contains("| Part 2 | panicked |  |  |"));
+        assert!(markdown.ends_with("No input at inputs/day3.txt.\n"));
+    }
+
+    #[test]
+    fn grid_answers_stay_out_of_table_rows() {
+        let grid = Answer::grid([[true, false, true], [false, true, false]]);
+        let result = RunResult {
+            parts: vec![PartResult { part: 2, answer: grid, elapsed: Duration::from_micros(4), peak_bytes: None, flamegraph: None }],
+            ..RunResult::default()
+        };
+        let day = DayReport {
+            day: 10,
+            input: PathBuf::from("inputs/day10.txt"),
+            result: Some(result),
+            checks: vec![Check::Fail { expected: "###\n...\n".to_owned() }],
+        };
+        let markdown = Report { year: 2025, days: vec![day] }.markdown();
+        assert!(markdown.contains("| 10 |  | <grid 3x2> ✗ |"));
+        assert!(markdown.contains("| Part 2 | <grid 3x2> | no | 4.00µs |"));
+        assert!(markdown.contains("Part 2:\n\n```\n#.#\n.#.\n```\n\nPart 2 expected:\n\n```\n###\n...\n```\n"));
+        assert!(markdown.lines().filter(|line| line.starts_with('|')).all(|line| line.ends_with('|')));
+    }
+
+    #[test]
+    fn html_is_escaped() {
+        let html = report().html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h2 id=\"day-1\">Day 1</h2>"));
+        assert!(html.contains("<td>no, expected &lt;5&gt;</td>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+}
@@ -0,0 +1,523 @@
+use std::{
+    fmt,
+    hint::black_box,
+    iter,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use tracing::{Span, info, info_span};
+
+/// A puzzle answer: usually a number, sometimes text, occasionally letters drawn in ASCII art.
+///
+/// Compares equal to a `&str` holding its printed form, which is how expected answers are kept.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    U64(u64),
+    I64(i64),
+    String(String),
+    /// Rows of ASCII art, printed one per line, for puzzles whose answer is the letters they spell.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Draws `rows` of lit cells as `#` on `.`.
+    pub fn grid<R>(rows: impl IntoIterator<Item = R>) -> Self
+    where R: IntoIterator<Item = bool>, {
+        Self::Grid(rows.into_iter().map(|row| row.into_iter().map(|lit| if lit { '#' } else { '.' }).collect()).collect())
+    }
+
+    /// The answer on one line, for table cells: grids become `<grid WxH>`, the rest print as
+    /// usual.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Grid(rows) => {
+                let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+                format!("<grid {width}x{}>", rows.len())
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U64(n) => n.fmt(f),
+            Self::I64(n) => n.fmt(f),
+            Self::String(text) => text.fmt(f),
+            Self::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    /// Grids ignore trailing whitespace on each line, as multi-line TOML strings tend to have it.
+    fn eq(&self, expected: &str) -> bool {
+        match self {
+            Self::Grid(rows) => rows.iter().map(|row| row.trim_end()).eq(expected.trim_end().lines().map(str::trim_end)),
+            Self::U64(n) => n.to_string() == expected,
+            Self::I64(n) => n.to_string() == expected,
+            Self::String(text) => text == expected,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, expected: &&str) -> bool {
+        self == *expected
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident: $($from:ty),*) => {
+        $(impl From<$from> for Answer {
+            fn from(value: $from) -> Self {
+                Self::$variant(value.into())
+            }
+        })*
+    };
+}
+
+answer_from!(U64: u8, u16, u32, u64);
+answer_from!(String: String, &str);
+
+/// Non-negative values become [`Answer::U64`], so the same number compares equal whichever
+/// integer type a solution used.
+macro_rules! answer_from_signed {
+    ($($from:ty),*) => {
+        $(impl From<$from> for Answer {
+            fn from(value: $from) -> Self {
+                u64::try_from(value).map_or_else(|_| Self::I64(value.into()), Self::U64)
+            }
+        })*
+    };
+}
+
+answer_from_signed!(i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::U64(value as u64)
+    }
+}
+
+/// Wide results keep every digit, falling back to a string once they outgrow 64 bits.
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        u64::try_from(value).map(Self::U64).or_else(|_| i64::try_from(value).map(Self::I64)).unwrap_or_else(|_| Self::String(value.to_string()))
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        u64::try_from(value).map_or_else(|_| Self::String(value.to_string()), Self::U64)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<aoc_utils::math::BigInt> for Answer {
+    fn from(value: aoc_utils::math::BigInt) -> Self {
+        u64::try_from(&value).map(Self::U64).or_else(|_| i64::try_from(&value).map(Self::I64)).unwrap_or_else(|_| Self::String(value.to_string()))
+    }
+}
+
+/// A day's solution, split so that parsing happens once and is shared by both parts.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Self::Parsed;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+
+    /// Parses `input` once and answers both parts, untimed.
+    #[must_use]
+    fn solve(input: &str) -> (Answer, Answer) {
+        let parsed = Self::parse(input);
+        (Self::part1(&parsed), Self::part2(&parsed))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// Peak extra heap usage, measured when the `alloc-stats` allocator is installed.
+    pub peak_bytes: Option<usize>,
+    /// An SVG flamegraph of the part, when [`RunOptions::profile`] asked for one and the
+    /// profiler caught at least one sample.
+    pub flamegraph: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunResult {
+    pub parse_elapsed: Duration,
+    pub parse_peak_bytes: Option<usize>,
+    pub parse_flamegraph: Option<Vec<u8>>,
+    /// The parts that finished, in the order requested.
+    pub parts: Vec<PartResult>,
+    /// The phase that exceeded [`RunOptions::timeout`], after which nothing else was run.
+    pub timed_out: Option<Phase>,
+    /// The phase that panicked (or was being warmed up when something did), after which nothing
+    /// else was run. The panic message has already gone to stderr.
+    pub panicked: Option<Phase>,
+}
+
+impl RunResult {
+    /// Parse time plus the time of every part.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+
+    fn record(&mut self, event: Event) {
+        match event {
+            Event::Parsed { elapsed, peak_bytes, flamegraph } => {
+                self.parse_elapsed = elapsed;
+                self.parse_peak_bytes = peak_bytes;
+                self.parse_flamegraph = flamegraph;
+            }
+            Event::Solved(part) => self.parts.push(part),
+        }
+    }
+}
+
+/// Summary of one phase's timings over repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation; zero for a single run.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises `samples`, or `None` if there are none.
+    #[must_use]
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let (&min, runs) = (sorted.first()?, sorted.len());
+        let median = if runs % 2 == 0 { (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2 } else { sorted[runs / 2] };
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = runs as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = if runs > 1 {
+            sorted.iter().map(|sample| (sample.as_secs_f64() - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        Some(Self {
+            runs,
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Untimed repetitions of the whole run before the measured one, so caches and the
+    /// allocator are warm.
+    pub warmup: u32,
+    /// Give up on any phase that takes longer than this.
+    pub timeout: Option<Duration>,
+    /// Sample each measured phase with `pprof` and render a flamegraph of it. Needs the
+    /// `profile` feature; without it no flamegraphs are made.
+    pub profile: bool,
+}
+
+enum Event {
+    Parsed { elapsed: Duration, peak_bytes: Option<usize>, flamegraph: Option<Vec<u8>> },
+    Solved(PartResult),
+}
+
+/// Parses `input` once and solves the requested `parts`, timing each phase.
+///
+/// With a timeout the solution runs on a worker thread; a phase that overruns is abandoned
+/// (the thread is left to finish in the background) and reported in [`RunResult::timed_out`].
+/// A phase that panics is reported in [`RunResult::panicked`].
+#[must_use]
+pub fn run<S: Solution + 'static>(input: &str, parts: &[u8], options: &RunOptions) -> RunResult {
+    let Some(timeout) = options.timeout else {
+        let (mut result, mut finished) = (RunResult::default(), 0);
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            solve::<S>(input, parts, options, |event| {
+                finished += 1;
+                result.record(event);
+            });
+        }));
+        if solved.is_err() {
+            result.panicked = phases(parts).nth(finished);
+        }
+        return result;
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let (owned_input, owned_parts, owned_options) = (input.to_owned(), parts.to_vec(), options.clone());
+    let span = Span::current();
+    let mut worker = Some(thread::spawn(move || {
+        let _entered = span.entered();
+        solve::<S>(&owned_input, &owned_parts, &owned_options, |event| {
+            let _ = sender.send(event);
+        });
+    }));
+
+    // Warm-up happens before the parse is reported, so the first wait covers every warm-up phase too.
+    let (warmup, phases_per_run) = (options.warmup, u32::try_from(parts.len()).unwrap_or(u32::MAX).saturating_add(1));
+    let first_budget = timeout.saturating_mul(warmup.saturating_mul(phases_per_run).saturating_add(1));
+
+    let mut result = RunResult::default();
+    for (i, phase) in phases(parts).enumerate() {
+        match receiver.recv_timeout(if i == 0 { first_budget } else { timeout }) {
+            Ok(event) => result.record(event),
+            Err(RecvTimeoutError::Timeout) => {
+                result.timed_out = Some(phase);
+                break;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                if worker.take().map(thread::JoinHandle::join).is_some_and(|joined| joined.is_err()) {
+                    result.panicked = Some(phase);
+                }
+                break;
+            }
+        }
+    }
+    result
+}
+
+fn phases(parts: &[u8]) -> impl Iterator<Item = Phase> {
+    iter::once(Phase::Parse).chain(parts.iter().map(|&part| Phase::Part(part)))
+}
+
+/// Solutions' own events are logged inside a `parse` or `part` span.
+fn solve<S: Solution>(input: &str, parts: &[u8], options: &RunOptions, mut emit: impl FnMut(Event)) {
+    if options.warmup > 0 {
+        info!(runs = options.warmup, "warming up");
+    }
+    for _ in 0..options.warmup {
+        let parsed = S::parse(black_box(input));
+        for &part in parts {
+            black_box(solver::<S>(part)(&parsed));
+        }
+    }
+
+    let (baseline, profiler) = (memory_baseline(), start_profiler(options.profile));
+    let start = Instant::now();
+    let parsed = info_span!("parse").in_scope(|| S::parse(input));
+    let elapsed = start.elapsed();
+    info!(?elapsed, "parsed");
+    emit(Event::Parsed { elapsed, peak_bytes: peak_bytes(baseline), flamegraph: flamegraph(profiler) });
+
+    for &part in parts {
+        let solve = solver::<S>(part);
+        let (baseline, profiler) = (memory_baseline(), start_profiler(options.profile));
+        let start = Instant::now();
+        let answer = info_span!("part", part).in_scope(|| solve(&parsed));
+        let elapsed = start.elapsed();
+        info!(part, %answer, ?elapsed, "solved");
+        let (peak_bytes, flamegraph) = (peak_bytes(baseline), flamegraph(profiler));
+        emit(Event::Solved(PartResult { part, answer, elapsed, peak_bytes, flamegraph }));
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn memory_baseline() -> Option<usize> {
+    crate::memory::start()
+}
+
+#[cfg(feature = "alloc-stats")]
+fn peak_bytes(baseline: Option<usize>) -> Option<usize> {
+    baseline.map(crate::memory::peak_since)
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+const fn memory_baseline() -> Option<usize> {
+    None
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+const fn peak_bytes(_baseline: Option<usize>) -> Option<usize> {
+    None
+}
+
+/// Samples per second while profiling; prime, so sampling doesn't fall into step with loops.
+#[cfg(feature = "profile")]
+const PROFILE_FREQUENCY: i32 = 997;
+
+#[cfg(feature = "profile")]
+type Profiler = pprof::ProfilerGuard<'static>;
+
+#[cfg(not(feature = "profile"))]
+type Profiler = ();
+
+#[cfg(feature = "profile")]
+fn start_profiler(enabled: bool) -> Option<Profiler> {
+    if !enabled {
+        return None;
+    }
+    pprof::ProfilerGuardBuilder::default()
+        .frequency(PROFILE_FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|e| tracing::warn!("failed to start the profiler: {e}"))
+        .ok()
+}
+
+#[cfg(feature = "profile")]
+fn flamegraph(profiler: Option<Profiler>) -> Option<Vec<u8>> {
+    let report = profiler?.report().build().map_err(|e| tracing::warn!("failed to build the profile: {e}")).ok()?;
+    let mut svg = Vec::new();
+    // Fails when nothing was sampled, i.e. the phase was over in a few milliseconds.
+    report.flamegraph(&mut svg).ok()?;
+    Some(svg)
+}
+
+#[cfg(not(feature = "profile"))]
+const fn start_profiler(_enabled: bool) -> Option<Profiler> {
+    None
+}
+
+#[cfg(not(feature = "profile"))]
+const fn flamegraph(_profiler: Option<Profiler>) -> Option<Vec<u8>> {
+    None
+}
+
+fn solver<S: Solution>(part: u8) -> fn(&S::Parsed) -> Answer {
+    if part == 1 { S::part1 } else { S::part2 }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use crate::solution::{Answer, Phase, RunOptions, Solution, Stats, run};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u64>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().sum::<u64>().into()
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Answer {
+            parsed.iter().product::<u64>().into()
+        }
+    }
+
+    #[test]
+    fn run_solves_requested_parts() {
+        let result = run::<Sum>("2\n3\n4\n", &[1, 2], &RunOptions::default());
+        assert_eq!(result.parts.iter().map(|p| (p.part, p.answer.to_string())).collect::<Vec<_>>(), vec![(1, "9".to_owned()), (2, "24".to_owned())]);
+
+        let result = run::<Sum>("2\n3\n4\n", &[2], &RunOptions { warmup: 3, ..RunOptions::default() });
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].answer, "24");
+        assert_eq!(result.total(), result.parse_elapsed + result.parts[0].elapsed);
+    }
+
+    #[test]
+    fn answers_compare_with_their_printed_form() {
+        assert_eq!(Answer::from(42_u32), "42");
+        assert_eq!(Answer::from(-7_i64), "-7");
+        assert_eq!(Answer::from("abc"), "abc");
+        assert_ne!(Answer::from(42_u64), "042");
+        assert_eq!(Answer::from(-7_i128), Answer::I64(-7));
+        assert_eq!(Answer::from(5_i64), Answer::from(5_u64));
+        assert_eq!(Answer::from(5_i32), Answer::from(5_i128));
+        assert_eq!(Answer::from(-5_i8), Answer::I64(-5));
+        assert_eq!(Answer::from(1_u128 << 64), "18446744073709551616");
+        assert_eq!(Answer::from(i128::MIN), i128::MIN.to_string().as_str());
+
+        let grid = Answer::grid([[true, false], [false, true]]);
+        assert_eq!(grid.to_string(), "#.\n.#");
+        assert_eq!((grid.summary(), Answer::from(42_u8).summary()), ("<grid 2x2>".to_owned(), "42".to_owned()));
+        assert_eq!(grid, "#.  \n.#\n");
+        assert_eq!(serde_json::to_string(&grid).unwrap(), r##"["#.",".#"]"##);
+        assert_eq!(serde_json::to_string(&Answer::from(3_u8)).unwrap(), "3");
+    }
+
+    #[test]
+    fn stats_summarise_samples() {
+        let ms = Duration::from_millis;
+        let stats = Stats::of(&[ms(4), ms(2), ms(8), ms(6)]).unwrap();
+        assert_eq!((stats.runs, stats.min, stats.median, stats.mean), (4, ms(2), ms(5), ms(5)));
+        assert!(stats.stddev.abs_diff(Duration::from_micros(2582)) < Duration::from_micros(1));
+
+        let single = Stats::of(&[ms(3)]).unwrap();
+        assert_eq!((single.median, single.stddev), (ms(3), Duration::ZERO));
+        assert!(Stats::of(&[]).is_none());
+    }
+
+    /// Part 1 answers straight away, part 2 takes as many milliseconds as the input says.
+    struct Slow;
+
+    impl Solution for Slow {
+        type Parsed = u64;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.trim().parse().unwrap()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Answer {
+            (*parsed).into()
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Answer {
+            assert!(*parsed > 0, "no delay");
+            std::thread::sleep(Duration::from_millis(*parsed));
+            (*parsed).into()
+        }
+    }
+
+    #[test]
+    fn slow_parts_time_out() {
+        let options = RunOptions { timeout: Some(Duration::from_millis(20)), ..RunOptions::default() };
+        let result = run::<Slow>("500", &[1, 2], &options);
+        assert_eq!(result.timed_out, Some(Phase::Part(2)));
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].answer, Answer::U64(500));
+
+        let options = RunOptions { warmup: 1, timeout: Some(Duration::from_secs(5)), ..RunOptions::default() };
+        let result = run::<Slow>("1", &[1, 2], &options);
+        assert_eq!(result.timed_out, None);
+        assert_eq!(result.parts.len(), 2);
+    }
+
+    #[test]
+    fn panics_are_reported() {
+        let result = run::<Slow>("0", &[1, 2], &RunOptions::default());
+        assert_eq!((result.panicked, result.parts.len()), (Some(Phase::Part(2)), 1));
+
+        let result = run::<Slow>("0", &[2], &RunOptions { timeout: Some(Duration::from_secs(5)), ..RunOptions::default() });
+        assert_eq!((result.panicked, result.parts.len()), (Some(Phase::Part(2)), 0));
+
+        let result = run::<Slow>("x", &[1], &RunOptions::default());
+        assert_eq!((result.panicked, result.timed_out), (Some(Phase::Parse), None));
+    }
+
+}
@@ -0,0 +1,131 @@
+use std::{
+    env,
+    fmt::{self, Debug, Display},
+    io::{self, IsTerminal},
+    sync::{
+        OnceLock,
+        atomic::{AtomicU8, Ordering},
+    },
+};
+
+const AUTO: u8 = 0;
+const ON: u8 = 1;
+const OFF: u8 = 2;
+
+static CHOICE: AtomicU8 = AtomicU8::new(AUTO);
+
+/// Forces colour on or off, e.g. for `--no-color`. Otherwise it is on when stdout is a terminal
+/// and `NO_COLOR` is unset or empty.
+pub fn set_enabled(enabled: bool) {
+    CHOICE.store(if enabled { ON } else { OFF }, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn enabled() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+    resolve(CHOICE.load(Ordering::Relaxed), || {
+        *DETECTED.get_or_init(|| env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()) && io::stdout().is_terminal())
+    })
+}
+
+/// Whether `choice` turns colour on, calling `detect` only when it was left to the terminal.
+fn resolve(choice: u8, detect: impl FnOnce() -> bool) -> bool {
+    match choice {
+        ON => true,
+        OFF => false,
+        _ => detect(),
+    }
+}
+
+/// A value printed inside an ANSI style when colour is enabled. Width and precision apply to the
+/// value itself, so styled cells still line up in tables.
+#[derive(Clone, Copy)]
+pub struct Styled<T> {
+    value: T,
+    code: &'static str,
+}
+
+impl<T> Styled<T> {
+    fn fmt_with(&self, f: &mut fmt::Formatter<'_>, fmt: fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result) -> fmt::Result {
+        self.fmt_as(enabled(), f, fmt)
+    }
+
+    fn fmt_as(&self, colour: bool, f: &mut fmt::Formatter<'_>, fmt: fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result) -> fmt::Result {
+        if !colour {
+            return fmt(&self.value, f);
+        }
+        write!(f, "\x1b[{}m", self.code)?;
+        fmt(&self.value, f)?;
+        f.write_str("\x1b[0m")
+    }
+}
+
+impl<T: Display> Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, Display::fmt)
+    }
+}
+
+/// `{:?}` styles the value's `Debug` output, which is how durations are printed.
+impl<T: Debug> Debug for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, Debug::fmt)
+    }
+}
+
+/// Answers and passing checks.
+pub const fn green<T>(value: T) -> Styled<T> {
+    Styled { value, code: "32" }
+}
+
+/// Failures and timeouts.
+pub const fn red<T>(value: T) -> Styled<T> {
+    Styled { value, code: "31" }
+}
+
+/// Timings and other secondary detail.
+pub const fn dim<T>(value: T) -> Styled<T> {
+    Styled { value, code: "2" }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::{
+        fmt::{self, Debug, Display},
+        time::Duration,
+    };
+
+    use crate::style::{AUTO, OFF, ON, Styled, dim, green, resolve};
+
+    /// Formats a [`Styled`] with colour decided here, leaving the global choice to other tests.
+    struct Forced<T>(Styled<T>, bool);
+
+    impl<T: Display> Display for Forced<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_as(self.1, f, Display::fmt)
+        }
+    }
+
+    impl<T: Debug> Debug for Forced<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_as(self.1, f, Debug::fmt)
+        }
+    }
+
+    #[test]
+    fn styles_wrap_the_padded_value() {
+        assert_eq!(format!("{:>4}", Forced(green(42), true)), "\x1b[32m  42\x1b[0m");
+        assert_eq!(format!("{:.1?}", Forced(dim(Duration::from_millis(1500)), true)), "\x1b[2m1.5s\x1b[0m");
+        assert_eq!(format!("{:>4}", Forced(green(42), false)), "  42");
+    }
+
+    #[test]
+    fn forced_choice_skips_detection() {
+        assert!(resolve(ON, || unreachable!()));
+        assert!(!resolve(OFF, || unreachable!()));
+        assert!(resolve(AUTO, || true));
+        assert!(!resolve(AUTO, || false));
+    }
+
+}
@@ -0,0 +1,272 @@
+use std::{fs, io, path::{Path, PathBuf}, time::{Duration, SystemTime}};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{calendar, fetch::{FetchError, require_session, session}, net};
+
+/// How adventofcode.com judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too recently (per the server, or a cooldown it imposed earlier); try again after this long.
+    Wait(Duration),
+    /// The part was already solved, or the previous part isn't yet.
+    WrongLevel,
+    /// The answer was rejected before, so it wasn't sent again.
+    PreviouslyRejected,
+    Unrecognized(String),
+}
+
+impl Verdict {
+    const fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Incorrect)
+    }
+}
+
+/// Classifies the HTML returned by the answer endpoint.
+#[must_use]
+pub fn parse_verdict(html: &str) -> Verdict {
+    if html.contains("That's the right answer") {
+        Verdict::Correct
+    } else if html.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if html.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if html.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::Wait(parse_wait(html))
+    } else if html.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        let text = html.find("<article>").map_or(html, |start| &html[start..]);
+        Verdict::Unrecognized(text.chars().take(200).collect())
+    }
+}
+
+/// Extracts the `4m 38s` from "You have 4m 38s left to wait".
+fn parse_wait(html: &str) -> Duration {
+    let Some(start) = html.find("You have ") else { return Duration::ZERO };
+    let rest = &html[start + "You have ".len()..];
+    let phrase = rest.find(" left to wait").map_or("", |end| &rest[..end]);
+    let seconds = phrase
+        .split_whitespace()
+        .filter_map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3_600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum();
+    Duration::from_secs(seconds)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+/// Every answer submitted so far and how it was judged, persisted as JSON in `.aoc/guesses.json`
+/// (`.aoc/<year>/` for other events).
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GuessLog {
+    guesses: Vec<Guess>,
+    /// Unix time before which the server asked us not to submit again.
+    cooldown_until: Option<u64>,
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl GuessLog {
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        calendar::year_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc")).join("guesses.json")
+    }
+
+    /// Loads the log, treating a missing file as empty.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::from),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[allow(clippy::missing_errors_doc)]
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_vec_pretty(self)?)
+    }
+
+    pub fn guesses(&self, day: u8, part: u8) -> impl Iterator<Item = &Guess> {
+        self.guesses.iter().filter(move |g| g.day == day && g.part == part)
+    }
+
+    #[must_use]
+    pub fn is_known_wrong(&self, day: u8, part: u8, answer: &str) -> bool {
+        self.guesses(day, part).any(|g| g.answer == answer && g.verdict.is_wrong())
+    }
+
+    /// How much longer the server-imposed cooldown lasts, if one is active.
+    #[must_use]
+    pub fn cooldown_remaining(&self, now: SystemTime) -> Option<Duration> {
+        let remaining = self.cooldown_until?.checked_sub(unix_seconds(now))?;
+        (remaining > 0).then(|| Duration::from_secs(remaining))
+    }
+
+    /// The exclusive `(lower, upper)` bounds implied by earlier "too low"/"too high" verdicts.
+    #[must_use]
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: Verdict| {
+            self.guesses(day, part).filter(move |g| g.verdict == verdict).filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        (numeric(Verdict::TooLow).max(), numeric(Verdict::TooHigh).min())
+    }
+
+    /// Explains why `answer` can't be right given earlier verdicts, if it can't.
+    #[must_use]
+    pub fn bounds_warning(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let value: i128 = answer.parse().ok()?;
+        match self.bounds(day, part) {
+            (Some(low), _) if value <= low => Some(format!("{answer} is not above {low}, which was too low")),
+            (_, Some(high)) if value >= high => Some(format!("{answer} is not below {high}, which was too high")),
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, verdict: Verdict, now: SystemTime) {
+        if let Verdict::Wait(wait) = verdict {
+            self.cooldown_until = Some(unix_seconds(now) + wait.as_secs());
+        }
+        self.guesses.push(Guess { day, part, answer: answer.to_owned(), verdict, at: unix_seconds(now) });
+    }
+}
+
+/// Posts `answer` for `day`/`part` using the session cookie and records the verdict.
+///
+/// Known-wrong answers are refused, and nothing is sent while a server cooldown is active.
+#[allow(clippy::missing_errors_doc)]
+pub fn submit_answer(day: u8, part: u8, answer: &str) -> Result<Verdict, FetchError> {
+    let session = session();
+    submit_answer_with(&GuessLog::default_path(), day, part, answer, session.as_deref())
+}
+
+fn submit_answer_with(log_path: &Path, day: u8, part: u8, answer: &str, session: Option<&str>) -> Result<Verdict, FetchError> {
+    let mut log = GuessLog::load(log_path).map_err(FetchError::Io)?;
+    if log.is_known_wrong(day, part, answer) {
+        return Ok(Verdict::PreviouslyRejected);
+    }
+    if let Some(remaining) = log.cooldown_remaining(SystemTime::now()) {
+        return Ok(Verdict::Wait(remaining));
+    }
+    let session = require_session(session)?;
+
+    let url = format!("https://adventofcode.com/{}/day/{day}/answer", calendar::year());
+    let html = net::client().post_form(&url, session, &[("level", &part.to_string()), ("answer", answer)])?;
+
+    let verdict = parse_verdict(&html);
+    log.record(day, part, answer, verdict.clone(), SystemTime::now());
+    log.save(log_path).map_err(FetchError::Io)?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::{Duration, SystemTime};
+
+    use crate::submit::{GuessLog, Verdict, parse_verdict, submit_answer_with};
+
+    #[test]
+    fn verdicts_are_recognized() {
+        assert_eq!(parse_verdict("<article><p>That's the right answer!  You are one gold star closer"), Verdict::Correct);
+        assert_eq!(parse_verdict("<p>That's not the right answer; your answer is too high.  If you're stuck"), Verdict::TooHigh);
+        assert_eq!(parse_verdict("<p>That's not the right answer; your answer is too low."), Verdict::TooLow);
+        assert_eq!(parse_verdict("<p>That's not the right answer.  If you're stuck"), Verdict::Incorrect);
+        assert_eq!(parse_verdict("<p>You don't seem to be solving the right level.  Did you already complete it?"), Verdict::WrongLevel);
+        assert!(matches!(parse_verdict("<html><article>Something new</article>"), Verdict::Unrecognized(text) if text.starts_with("<article>Something")));
+    }
+
+    #[test]
+    fn wait_times_are_parsed() {
+        let html = "<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 38s left to wait.";
+        assert_eq!(parse_verdict(html), Verdict::Wait(Duration::from_secs(278)));
+        assert_eq!(parse_verdict("You gave an answer too recently. You have 39s left to wait."), Verdict::Wait(Duration::from_secs(39)));
+    }
+
+    fn temp_log(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc2025-{}-{name}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn known_wrong_answers_are_not_resubmitted() {
+        let path = temp_log("guesses-wrong");
+        let mut log = GuessLog::default();
+        log.record(1, 2, "1234", Verdict::TooHigh, SystemTime::UNIX_EPOCH);
+        log.save(&path).unwrap();
+
+        assert_eq!(submit_answer_with(&path, 1, 2, "1234", None).unwrap(), Verdict::PreviouslyRejected);
+        // A different part or answer still goes through to the session check.
+        assert!(submit_answer_with(&path, 1, 1, "1234", None).is_err());
+        assert!(submit_answer_with(&path, 1, 2, "999", None).is_err());
+    }
+
+    #[test]
+    fn cooldown_blocks_submission() {
+        let path = temp_log("guesses-cooldown");
+        let mut log = GuessLog::default();
+        log.record(1, 1, "5", Verdict::Wait(Duration::from_mins(10)), SystemTime::now());
+        log.save(&path).unwrap();
+
+        assert!(matches!(submit_answer_with(&path, 1, 1, "6", None), Ok(Verdict::Wait(remaining)) if remaining > Duration::from_mins(9)));
+        assert_eq!(log.cooldown_remaining(SystemTime::now() + Duration::from_mins(11)), None);
+    }
+
+    #[test]
+    fn bounds_come_from_earlier_verdicts() {
+        let mut log = GuessLog::default();
+        assert_eq!(log.bounds(1, 1), (None, None));
+        log.record(1, 1, "100", Verdict::TooLow, SystemTime::UNIX_EPOCH);
+        log.record(1, 1, "150", Verdict::TooLow, SystemTime::UNIX_EPOCH);
+        log.record(1, 1, "300", Verdict::TooHigh, SystemTime::UNIX_EPOCH);
+        log.record(1, 2, "1", Verdict::TooHigh, SystemTime::UNIX_EPOCH);
+
+        assert_eq!(log.bounds(1, 1), (Some(150), Some(300)));
+        assert_eq!(log.bounds_warning(1, 1, "200"), None);
+        assert!(log.bounds_warning(1, 1, "120").unwrap().contains("too low"));
+        assert!(log.bounds_warning(1, 1, "300").unwrap().contains("too high"));
+        assert_eq!(log.bounds_warning(1, 1, "ABC"), None);
+    }
+
+    #[test]
+    fn log_round_trips() {
+        let path = temp_log("guesses-round-trip");
+        assert_eq!(GuessLog::load(&path).unwrap().guesses(1, 1).count(), 0);
+
+        let mut log = GuessLog::default();
+        log.record(3, 1, "42", Verdict::Correct, SystemTime::UNIX_EPOCH);
+        log.save(&path).unwrap();
+        let loaded = GuessLog::load(&path).unwrap();
+        assert_eq!(loaded.guesses(3, 1).next().unwrap().verdict, Verdict::Correct);
+    }
+
+}